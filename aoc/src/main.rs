@@ -0,0 +1,88 @@
+use std::{path::PathBuf, process::ExitCode, time::Instant};
+
+use aoc_utils::Registry;
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solver for a single day and part.
+    Run {
+        #[arg(short, long)]
+        day: u8,
+        #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Defaults to the `input` file in the day's directory.
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// List the days with a registered solver.
+    List,
+}
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .register(d01::solver())
+        .register(d02::solver())
+        .register(d03::solver())
+        .register(d04::solver())
+        .register(d05::solver())
+        .register(d06::solver())
+        .register(d07::solver())
+        .register(d08::solver())
+        .register(d09::solver())
+        .register(d10::solver())
+        .register(d11::solver())
+        .register(d12::solver())
+        .register(d13::solver())
+        .register(d14::solver())
+        .register(d15::solver())
+        .register(d16::solver())
+        .register(d17::solver())
+        .register(d18::solver())
+        .register(d19::solver())
+        .register(d20::solver())
+        .register(d21::solver());
+    registry
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let registry = registry();
+
+    match cli.command {
+        Command::Run { day, part, input } => {
+            let Some(solver) = registry.get(day) else {
+                eprintln!("No solver registered for day {day}");
+                return ExitCode::FAILURE;
+            };
+            let input = input.unwrap_or_else(|| PathBuf::from(format!("d{day:02}/input")));
+
+            let start = Instant::now();
+            let result = match solver.solve(part == 2, &input) {
+                Ok(result) => result,
+                Err(e) => {
+                    eprintln!("{e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let elapsed = start.elapsed();
+
+            println!("Day {day} part {part}: {result}");
+            println!("Took {elapsed:?}");
+        }
+        Command::List => {
+            for day in registry.days() {
+                println!("Day {day}");
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,185 @@
+//! Detecting when repeatedly applying a function starts going round in circles.
+//!
+//! Each detector walks `start, f(start), f(f(start)), ...` until a state
+//! repeats, which lets the caller jump to the state at a huge index without
+//! visiting everything in between.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Where a sequence of states starts looping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// How many states come before the loop starts.
+    pub prefix: usize,
+    /// How many states make up the loop.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest index holding the same state as index `n`.
+    #[must_use]
+    pub const fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.length
+        }
+    }
+
+    /// The state at index `n`, found by stepping from `start` no further than the loop.
+    pub fn state_at<T: Clone>(&self, start: &T, mut f: impl FnMut(&T) -> T, n: usize) -> T {
+        let mut state = start.clone();
+        for _ in 0..self.reduce(n) {
+            state = f(&state);
+        }
+        state
+    }
+}
+
+/// A `Cycle` along with every state seen up to the end of its first loop.
+#[derive(Debug, Clone)]
+pub struct History<T> {
+    pub cycle: Cycle,
+    states: Vec<T>,
+}
+
+impl<T> History<T> {
+    /// The state at index `n`, however large.
+    #[must_use]
+    pub fn state_at(&self, n: usize) -> &T {
+        &self.states[self.cycle.reduce(n)]
+    }
+
+    /// Every state from the start to the end of the first loop.
+    #[must_use]
+    pub fn states(&self) -> &[T] {
+        &self.states
+    }
+}
+
+/// Finds the cycle by remembering every state, so costs memory but calls `f`
+/// only once per state.
+pub fn hashed<T: Clone + Eq + Hash>(start: T, mut f: impl FnMut(&T) -> T) -> History<T> {
+    let mut seen: HashMap<T, usize> = HashMap::new();
+    let mut states = vec![];
+    let mut state = start;
+
+    loop {
+        if let Some(&prefix) = seen.get(&state) {
+            let cycle = Cycle {
+                prefix,
+                length: states.len() - prefix,
+            };
+            return History { cycle, states };
+        }
+        seen.insert(state.clone(), states.len());
+        let next = f(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+/// Finds the cycle with Floyd's tortoise and hare, holding only two states at once.
+pub fn floyd<T: Clone + PartialEq>(start: &T, mut f: impl FnMut(&T) -> T) -> Cycle {
+    let mut tortoise = f(start);
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        hare = f(&hare);
+    }
+
+    let mut prefix = 0;
+    tortoise = start.clone();
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        prefix += 1;
+    }
+
+    let mut length = 1;
+    hare = f(&tortoise);
+    while tortoise != hare {
+        hare = f(&hare);
+        length += 1;
+    }
+
+    Cycle { prefix, length }
+}
+
+/// Finds the cycle with Brent's algorithm, which usually calls `f` less often than `floyd`.
+pub fn brent<T: Clone + PartialEq>(start: &T, mut f: impl FnMut(&T) -> T) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = f(start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = f(&hare);
+        length += 1;
+    }
+
+    let mut prefix = 0;
+    tortoise = start.clone();
+    hare = start.clone();
+    for _ in 0..length {
+        hare = f(&hare);
+    }
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, length }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 3, 10, 101, 2, 5, 26, 167, 95, 101, ... loops back to 101 at index 8.
+    fn step(x: &u32) -> u32 {
+        (x * x + 1) % 255
+    }
+
+    const EXPECTED: Cycle = Cycle {
+        prefix: 2,
+        length: 6,
+    };
+
+    #[test]
+    fn detectors_agree() {
+        assert_eq!(hashed(3, step).cycle, EXPECTED);
+        assert_eq!(floyd(&3, step), EXPECTED);
+        assert_eq!(brent(&3, step), EXPECTED);
+    }
+
+    #[test]
+    fn state_at_matches_brute_force() {
+        let history = hashed(3, step);
+        let mut state = 3;
+        for n in 0..50 {
+            assert_eq!(*history.state_at(n), state, "index {n}");
+            assert_eq!(EXPECTED.state_at(&3, step, n), state, "index {n}");
+            state = step(&state);
+        }
+    }
+
+    #[test]
+    fn loop_from_the_start() {
+        let cycle = brent(&0, |x| (x + 1) % 4);
+        assert_eq!(
+            cycle,
+            Cycle {
+                prefix: 0,
+                length: 4
+            }
+        );
+        assert_eq!(cycle.reduce(1_000_000_001), 1);
+    }
+}
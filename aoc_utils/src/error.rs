@@ -0,0 +1,165 @@
+use std::{
+    error::Error,
+    fmt, io,
+    num::ParseIntError,
+    path::{Path, PathBuf},
+    process,
+};
+
+pub type PuzzleResult<T> = Result<T, PuzzleError>;
+
+#[derive(Debug)]
+pub enum PuzzleErrorKind {
+    Io(io::Error),
+    UnexpectedChar(char),
+    Malformed(String),
+}
+
+impl fmt::Display for PuzzleErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{e}"),
+            Self::UnexpectedChar(c) => write!(f, "unexpected character {c:?}"),
+            Self::Malformed(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+/// An error reading or parsing the puzzle input.
+///
+/// Line and column are 1-based and, like the path, are filled in by whichever
+/// reader knows them. Each location is only ever set once, so the innermost,
+/// most precise position wins.
+#[derive(Debug)]
+pub struct PuzzleError {
+    kind: PuzzleErrorKind,
+    path: Option<PathBuf>,
+    line: Option<usize>,
+    column: Option<usize>,
+}
+
+impl PuzzleError {
+    #[must_use]
+    pub const fn new(kind: PuzzleErrorKind) -> Self {
+        Self {
+            kind,
+            path: None,
+            line: None,
+            column: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn unexpected_char(c: char) -> Self {
+        Self::new(PuzzleErrorKind::UnexpectedChar(c))
+    }
+
+    #[must_use]
+    pub fn malformed(message: impl Into<String>) -> Self {
+        Self::new(PuzzleErrorKind::Malformed(message.into()))
+    }
+
+    #[must_use]
+    pub fn with_path(mut self, path: impl AsRef<Path>) -> Self {
+        self.path.get_or_insert_with(|| path.as_ref().to_path_buf());
+        self
+    }
+
+    #[must_use]
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    #[must_use]
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    #[must_use]
+    pub const fn kind(&self) -> &PuzzleErrorKind {
+        &self.kind
+    }
+
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    #[must_use]
+    pub const fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    #[must_use]
+    pub const fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    /// Reports the error on stderr and exits, for binaries with nothing better to do.
+    pub fn exit(&self) -> ! {
+        eprintln!("{self}");
+        process::exit(1)
+    }
+}
+
+impl fmt::Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{}:", path.display())?;
+        }
+        if let Some(line) = self.line {
+            write!(f, "{line}:")?;
+            if let Some(column) = self.column {
+                write!(f, "{column}:")?;
+            }
+        }
+        if self.path.is_some() || self.line.is_some() {
+            write!(f, " ")?;
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+impl Error for PuzzleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            PuzzleErrorKind::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for PuzzleError {
+    fn from(error: io::Error) -> Self {
+        Self::new(PuzzleErrorKind::Io(error))
+    }
+}
+
+impl From<ParseIntError> for PuzzleError {
+    fn from(error: ParseIntError) -> Self {
+        Self::malformed(format!("invalid number: {error}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_full_location() {
+        let error = PuzzleError::unexpected_char('x')
+            .at_column(4)
+            .at_line(2)
+            .with_path("input");
+        assert_eq!(error.to_string(), "input:2:4: unexpected character 'x'");
+    }
+
+    #[test]
+    fn innermost_location_wins() {
+        let error = PuzzleError::malformed("bad").at_line(3).at_line(1);
+        assert_eq!(error.line(), Some(3));
+        assert_eq!(error.to_string(), "3: bad");
+    }
+}
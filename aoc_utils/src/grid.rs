@@ -0,0 +1,286 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::{out_of_bounds, puzzle_matrix, Coord, FromChar, PuzzleError, PuzzleResult};
+
+const NEIGHBOURS: [Coord; 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const NEIGHBOURS8: [Coord; 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+/// A rectangular grid stored flat in row-major order.
+///
+/// Coordinates are `(row, column)` like everywhere else in the puzzles, so a
+/// negative or too large coordinate is simply outside the grid.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// # Panics
+    /// If there aren't exactly `width * height` cells.
+    #[must_use]
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "grid cells don't fill the grid"
+        );
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Builds a grid from its rows.
+    ///
+    /// # Errors
+    /// If the rows aren't all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> PuzzleResult<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut cells = Vec::with_capacity(width * height);
+        for (i, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(PuzzleError::malformed(format!(
+                    "expected a row of length {width}, found {}",
+                    row.len()
+                ))
+                .at_line(i + 1));
+            }
+            cells.extend(row);
+        }
+        Ok(Self::new(width, height, cells))
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub const fn contains(&self, coord: Coord) -> bool {
+        !out_of_bounds(&coord, self.width, self.height)
+    }
+
+    const fn offset(&self, coord: Coord) -> Option<usize> {
+        if self.contains(coord) {
+            Some(coord.0 as usize * self.width + coord.1 as usize)
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.offset(coord).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.offset(coord).map(|i| &mut self.cells[i])
+    }
+
+    /// The up to four orthogonal neighbours of `coord` inside the grid.
+    pub fn neighbours(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.offsets_from(coord, &NEIGHBOURS)
+    }
+
+    /// The up to eight neighbours of `coord` inside the grid, diagonals included.
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.offsets_from(coord, &NEIGHBOURS8)
+    }
+
+    fn offsets_from<'a>(
+        &'a self,
+        coord: Coord,
+        offsets: &'static [Coord],
+    ) -> impl Iterator<Item = Coord> + 'a {
+        offsets
+            .iter()
+            .map(move |d| (coord.0 + d.0, coord.1 + d.1))
+            .filter(|c| self.contains(*c))
+    }
+
+    /// # Panics
+    /// If `row` is outside the grid.
+    #[must_use]
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height, "row {row} is outside the grid");
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|i| self.row(i))
+    }
+
+    /// # Panics
+    /// If `column` is outside the grid.
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.width, "column {column} is outside the grid");
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|j| self.column(j))
+    }
+
+    /// Every cell along with its coordinate, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, t)| (((i / width) as isize, (i % width) as isize), t))
+    }
+
+    /// The coordinate of the first cell, row by row, matching `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Coord> {
+        self.iter().find(|(_, t)| predicate(t)).map(|(c, _)| c)
+    }
+
+    #[must_use]
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Builds a new grid by looking up each of its coordinates in this one.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> Coord) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|i| (0..width).map(move |j| (i, j)))
+            .map(|(i, j)| self[source(i, j)].clone())
+            .collect();
+        Self::new(width, height, cells)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |i, j| (j as isize, i as isize))
+    }
+
+    #[must_use]
+    pub fn rotate_clockwise(&self) -> Self {
+        let height = self.height;
+        self.remap(self.height, self.width, |i, j| {
+            ((height - 1 - j) as isize, i as isize)
+        })
+    }
+
+    #[must_use]
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        let width = self.width;
+        self.remap(self.height, self.width, |i, j| {
+            (j as isize, (width - 1 - i) as isize)
+        })
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        self.get(coord)
+            .unwrap_or_else(|| panic!("{coord:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{coord:?} is outside the grid"))
+    }
+}
+
+impl<T: FromChar> FromStr for Grid<T> {
+    type Err = PuzzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_rows(puzzle_matrix(s)?)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef\n".parse().unwrap()
+    }
+
+    #[test]
+    fn dimensions_and_lookup() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((1, 2)), Some(&'f'));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, -1)), None);
+        assert_eq!(grid.find(|c| *c == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = grid();
+        let mut four: Vec<_> = grid.neighbours((0, 0)).collect();
+        four.sort_unstable();
+        assert_eq!(four, [(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((0, 1)).count(), 5);
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+    }
+
+    #[test]
+    fn ragged_rows() {
+        let error = "abc\nde\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(error.line(), Some(2));
+    }
+}
@@ -0,0 +1,312 @@
+//! Sets of values stored as ranges, for puzzles where the values are far too
+//! many to handle one at a time.
+
+use std::ops::{Add, Mul, Range, Sub};
+
+/// Whether two half-open ranges share any value.
+#[must_use]
+pub fn overlaps<T: Ord>(a: &Range<T>, b: &Range<T>) -> bool {
+    a.start < b.end && b.start < a.end && a.start < a.end && b.start < b.end
+}
+
+/// A set of values held as sorted, disjoint, non-adjacent half-open ranges.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sorts and merges `ranges` so that equal sets always compare equal.
+    fn normalise(mut ranges: Vec<Range<T>>) -> Self {
+        ranges.retain(|r| r.start < r.end);
+        ranges.sort_by_key(|r| r.start);
+
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+        *self = Self::normalise(ranges);
+    }
+
+    #[must_use]
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool {
+        self.ranges.iter().any(|r| r.contains(value))
+    }
+
+    /// The smallest value in the set.
+    #[must_use]
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalise(self.ranges.iter().chain(&other.ranges).cloned().collect())
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut j = 0;
+        for range in &self.ranges {
+            let mut start = range.start;
+            while let Some(cut) = other.ranges.get(j) {
+                if cut.end <= start {
+                    j += 1;
+                    continue;
+                }
+                if cut.start >= range.end {
+                    break;
+                }
+                if start < cut.start {
+                    ranges.push(start..cut.start);
+                }
+                start = cut.end;
+                if cut.end > range.end {
+                    break;
+                }
+                j += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Splits into the values below `at` and the values from `at` upwards.
+    #[must_use]
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let mut below = vec![];
+        let mut above = vec![];
+        for range in &self.ranges {
+            if range.end <= at {
+                below.push(range.clone());
+            } else if range.start >= at {
+                above.push(range.clone());
+            } else {
+                below.push(range.start..at);
+                above.push(at..range.end);
+            }
+        }
+        (Self { ranges: below }, Self { ranges: above })
+    }
+
+    /// Moves every value by the offset that takes `from` to `to`.
+    ///
+    /// Written this way round so that unsigned sets can move down as well as
+    /// up, as long as no value is below `from`.
+    #[must_use]
+    pub fn shift(&self, from: T, to: T) -> Self
+    where
+        T: Add<Output = T> + Sub<Output = T>,
+    {
+        let ranges = self
+            .ranges
+            .iter()
+            .map(|r| r.start - from + to..r.end - from + to)
+            .collect();
+        Self { ranges }
+    }
+
+    /// How many values are in the set.
+    #[must_use]
+    pub fn cardinality(&self) -> T
+    where
+        T: Add<Output = T> + Sub<Output = T> + Default,
+    {
+        self.ranges
+            .iter()
+            .fold(T::default(), |total, r| total + (r.end - r.start))
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::normalise(vec![range])
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        Self::normalise(iter.into_iter().collect())
+    }
+}
+
+/// A box of values spanning a half-open range along each of `N` axes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HyperRect<T, const N: usize> {
+    pub axes: [Range<T>; N],
+}
+
+impl<T: Copy + Ord, const N: usize> HyperRect<T, N> {
+    #[must_use]
+    pub const fn new(axes: [Range<T>; N]) -> Self {
+        Self { axes }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(|r| r.start >= r.end)
+    }
+
+    #[must_use]
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        self.axes.iter().zip(point).all(|(r, p)| r.contains(p))
+    }
+
+    /// How many points lie inside the box.
+    #[must_use]
+    pub fn volume(&self) -> T
+    where
+        T: Sub<Output = T> + Mul<Output = T> + From<u8>,
+    {
+        if self.is_empty() {
+            return T::from(0);
+        }
+        self.axes
+            .iter()
+            .fold(T::from(1), |volume, r| volume * (r.end - r.start))
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut axes = self.axes.clone();
+        for (axis, r) in axes.iter_mut().zip(&other.axes) {
+            *axis = axis.start.max(r.start)..axis.end.min(r.end);
+        }
+        let rect = Self { axes };
+        (!rect.is_empty()).then_some(rect)
+    }
+
+    /// Cuts the box along `axis` into the part below `at` and the part from
+    /// `at` upwards, either of which may be empty.
+    ///
+    /// # Panics
+    /// If `axis` isn't less than `N`.
+    #[must_use]
+    pub fn split_at(&self, axis: usize, at: T) -> (Option<Self>, Option<Self>) {
+        let range = &self.axes[axis];
+        let mut below = self.clone();
+        below.axes[axis] = range.start..at.min(range.end);
+        let mut above = self.clone();
+        above.axes[axis] = at.max(range.start)..range.end;
+
+        (
+            (!below.is_empty()).then_some(below),
+            (!above.is_empty()).then_some(above),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<u64>]) -> IntervalSet<u64> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn normalises_ranges() {
+        let set = set(&[10..20, 0..5, 15..25, 5..7, 30..30]);
+        assert_eq!(set.ranges(), &[0..7, 10..25]);
+        assert_eq!(set.cardinality(), 22);
+        assert_eq!(set.min(), Some(0));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = IntervalSet::from(5..25);
+        assert_eq!(a.union(&b), IntervalSet::from(0..30));
+        assert_eq!(a.intersection(&b), set(&[5..10, 20..25]));
+        assert_eq!(a.difference(&b), set(&[0..5, 25..30]));
+        assert_eq!(b.difference(&a), IntervalSet::from(10..20));
+        assert!(a.difference(&IntervalSet::from(0..40)).is_empty());
+    }
+
+    #[test]
+    fn split_and_shift() {
+        let a = set(&[0..10, 20..30]);
+        let (below, above) = a.split_at(25);
+        assert_eq!(below, set(&[0..10, 20..25]));
+        assert_eq!(above, IntervalSet::from(25..30));
+        assert_eq!(above.shift(25, 3), IntervalSet::from(3..8));
+    }
+
+    #[test]
+    fn overlapping_ranges() {
+        assert!(overlaps(&(0..5), &(4..8)));
+        assert!(!overlaps(&(0..5), &(5..8)));
+        assert!(!overlaps(&(3..3), &(0..8)));
+    }
+
+    #[test]
+    fn hyper_rect() {
+        let rect = HyperRect::new([1..5, 1..3]);
+        assert_eq!(rect.volume(), 8_u64);
+        assert!(rect.contains(&[4, 2]));
+
+        let (below, above) = rect.split_at(0, 3);
+        assert_eq!(below, Some(HyperRect::new([1..3, 1..3])));
+        assert_eq!(above, Some(HyperRect::new([3..5, 1..3])));
+        assert_eq!(rect.split_at(1, 10), (Some(rect.clone()), None));
+
+        let other = HyperRect::new([4..9, 0..2]);
+        assert_eq!(
+            rect.intersection(&other),
+            Some(HyperRect::new([4..5, 1..2]))
+        );
+        assert_eq!(rect.intersection(&HyperRect::new([5..9, 0..2])), None);
+    }
+}
@@ -1,16 +1,39 @@
 use std::{
     fs::File,
-    io::{BufRead, BufReader, Lines},
-    path::Path,
+    io::{self, BufRead, BufReader, Lines, Read},
+    path::{Path, PathBuf},
 };
 
-use clap::Parser;
+use clap::{Args, Parser};
 
+pub mod cycle;
+mod error;
+mod grid;
+pub mod interval;
+mod registry;
+pub mod search;
+mod solution;
+
+pub use error::{PuzzleError, PuzzleErrorKind, PuzzleResult};
+pub use grid::Grid;
+pub use registry::{Registry, Solver, SolverFn};
+pub use solution::Solution;
+
+// The command line shared by every day. Days with their own settings pass
+// them as `P`, usually the day's `Solution` type deriving `clap::Args`, or a
+// `Params` struct in the day's main for settings that don't fit on it, and
+// they're flattened in. Not a doc comment as clap would show it as the about.
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
-pub struct Cli {
+pub struct Cli<P: Args = NoParams> {
     #[arg(short, long)]
     pub part_two: bool,
+    /// The puzzle input, or `-` to read it from stdin. Defaults to the day's
+    /// `input` file.
+    #[arg(short, long)]
+    pub input: Option<PathBuf>,
+    #[command(flatten)]
+    pub params: P,
 }
 
 impl Cli {
@@ -20,26 +43,153 @@ impl Cli {
     }
 }
 
-pub type PuzzleLines = Lines<BufReader<File>>;
+impl<P: Args> Cli<P> {
+    /// Parses the command line along with a day's own settings.
+    #[must_use]
+    pub fn parse_with_params() -> Self {
+        Self::parse()
+    }
+
+    /// Where to read the puzzle from, `-` meaning stdin.
+    #[must_use]
+    pub fn input_path(&self) -> &Path {
+        self.input.as_deref().unwrap_or_else(|| Path::new("input"))
+    }
+}
+
+/// Settings for a day that doesn't have any.
+#[derive(Debug, Clone, Copy, Default, Args)]
+pub struct NoParams {}
+
+/// The path that stands for stdin rather than a file.
+fn is_stdin(input: &Path) -> bool {
+    input == Path::new("-")
+}
+
+pub type PuzzleLines = Lines<Box<dyn BufRead>>;
+
+/// Opens the puzzle input for reading line by line.
+///
+/// # Errors
+/// If the file can't be opened.
+pub fn puzzle_input_lines<P: AsRef<Path>>(input: P) -> PuzzleResult<PuzzleLines> {
+    let input = input.as_ref();
+    let reader: Box<dyn BufRead> = if is_stdin(input) {
+        Box::new(io::stdin().lock())
+    } else {
+        let file = File::open(input).map_err(|e| PuzzleError::from(e).with_path(input))?;
+        Box::new(BufReader::new(file))
+    };
+    Ok(reader.lines())
+}
+
+/// Reads every line of the puzzle input.
+///
+/// # Errors
+/// If the file can't be opened or a line isn't valid UTF-8.
+pub fn get_entire_puzzle(filename: impl AsRef<Path>) -> PuzzleResult<Vec<String>> {
+    let filename = filename.as_ref();
+    puzzle_input_lines(filename)?
+        .enumerate()
+        .map(|(i, l)| l.map_err(|e| PuzzleError::from(e).at_line(i + 1).with_path(filename)))
+        .collect()
+}
+
+/// Reads the whole puzzle input into a single string.
+///
+/// # Errors
+/// If the file can't be read.
+pub fn read_puzzle(input: impl AsRef<Path>) -> PuzzleResult<String> {
+    let input = input.as_ref();
+    let puzzle = if is_stdin(input) {
+        read_puzzle_from(io::stdin())
+    } else {
+        File::open(input)
+            .map_err(PuzzleError::from)
+            .and_then(read_puzzle_from)
+    };
+    puzzle.map_err(|e| e.with_path(input))
+}
+
+/// Reads the whole puzzle from `reader`, which may well be stdin.
+///
+/// # Errors
+/// If reading fails or the input isn't valid UTF-8.
+pub fn read_puzzle_from(mut reader: impl Read) -> PuzzleResult<String> {
+    let mut puzzle = String::new();
+    reader.read_to_string(&mut puzzle)?;
+    Ok(puzzle)
+}
 
-#[allow(clippy::missing_panics_doc)]
-pub fn puzzle_input_lines<P: AsRef<Path>>(input: P) -> PuzzleLines {
-    let file = File::open(input).expect("Could not find file {input}");
-    let reader = BufReader::new(file);
-    reader.lines()
+/// Parses every line of the puzzle input with `parse_line`.
+///
+/// # Errors
+/// If the file can't be read or `parse_line` fails, in which case the
+/// error is tagged with the path and line number.
+pub fn parse_puzzle_lines<T>(
+    input: impl AsRef<Path>,
+    parse_line: impl FnMut(&str) -> PuzzleResult<T>,
+) -> PuzzleResult<Vec<T>> {
+    parse_puzzle_file(input, |puzzle| parse_lines(puzzle, parse_line))
 }
 
-#[allow(clippy::missing_panics_doc)]
-pub fn get_entire_puzzle(filename: impl AsRef<Path>) -> Vec<String> {
-    let file = File::open(filename).expect("no such file");
-    let buf = BufReader::new(file);
-    buf.lines()
-        .map(|l| l.expect("Could not parse line"))
+/// Parses every line of `input` with `parse_line`.
+///
+/// # Errors
+/// If `parse_line` fails, in which case the error is tagged with the line number.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse_line: impl FnMut(&str) -> PuzzleResult<T>,
+) -> PuzzleResult<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| parse_line(l).map_err(|e| e.at_line(i + 1)))
         .collect()
 }
 
-pub trait FromChar {
-    fn from_char(c: char) -> Self;
+/// Runs a nom parser over the whole of `line`, pointing any error, including
+/// anything left over, at its column.
+///
+/// # Errors
+/// If `parser` fails or doesn't consume all of `line`.
+pub fn parse_nom_line<'a, T>(
+    line: &'a str,
+    parser: impl FnMut(&'a str) -> nom::IResult<&'a str, T>,
+) -> PuzzleResult<T> {
+    match nom::combinator::all_consuming(parser)(line) {
+        Ok((_, value)) => Ok(value),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            // nom hands back the unparsed remainder, which is always a slice of `line`.
+            let column = e.input.as_ptr() as usize - line.as_ptr() as usize + 1;
+            Err(PuzzleError::malformed(format!("invalid syntax ({:?})", e.code)).at_column(column))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(PuzzleError::malformed("incomplete line")),
+    }
+}
+
+/// Parses the whole puzzle input with `parse`, tagging any error with the path.
+///
+/// # Errors
+/// If the file can't be read or `parse` fails.
+pub fn parse_puzzle_file<T>(
+    input: impl AsRef<Path>,
+    parse: impl FnOnce(&str) -> PuzzleResult<T>,
+) -> PuzzleResult<T> {
+    let input = input.as_ref();
+    parse(&read_puzzle(input)?).map_err(|e| e.with_path(input))
+}
+
+pub trait FromChar: Sized {
+    /// # Errors
+    /// If `c` doesn't represent any value of the type.
+    fn from_char(c: char) -> PuzzleResult<Self>;
+}
+
+impl FromChar for char {
+    fn from_char(c: char) -> PuzzleResult<Self> {
+        Ok(c)
+    }
 }
 
 /// Parses the puzzle input as a matrix of type T.
@@ -47,22 +197,27 @@ pub trait FromChar {
 /// Type T is usually an enum and implements `FromChar` which
 /// takes each character in the input and maps it to an enum variant.
 ///
-/// # Panics
-/// Assumes the puzzle has a blank line at the bottom.
-#[must_use]
-pub fn puzzle_matrix<T: FromChar>(input: &str) -> Vec<Vec<T>> {
-    let input = input.strip_suffix('\n').unwrap();
-
+/// # Errors
+/// If any character is rejected by `FromChar`, tagged with its line and column.
+pub fn puzzle_matrix<T: FromChar>(input: &str) -> PuzzleResult<Vec<Vec<T>>> {
     input
-        .split('\n')
-        .map(|p| p.chars().map(T::from_char).collect())
+        .lines()
+        .enumerate()
+        .map(|(i, l)| {
+            l.chars()
+                .enumerate()
+                .map(|(j, c)| T::from_char(c).map_err(|e| e.at_column(j + 1).at_line(i + 1)))
+                .collect()
+        })
         .collect()
 }
 
 pub type Coord = (isize, isize);
 
-pub const fn out_of_bounds(coord: &Coord, size: isize) -> bool {
-    coord.0 < 0 || coord.1 < 0 || coord.0 >= size || coord.1 >= size
+/// Whether `coord` lies outside a grid of `width` columns and `height` rows.
+#[must_use]
+pub const fn out_of_bounds(coord: &Coord, width: usize, height: usize) -> bool {
+    coord.0 < 0 || coord.1 < 0 || coord.0 as usize >= height || coord.1 as usize >= width
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
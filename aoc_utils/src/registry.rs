@@ -0,0 +1,79 @@
+use std::{collections::BTreeMap, path::Path};
+
+use crate::{PuzzleResult, Solution};
+
+/// Entry point for a single part of a day's puzzle, rendering the answer as text.
+pub type SolverFn = fn(&Path) -> PuzzleResult<String>;
+
+/// The part one and part two entry points of a single day.
+#[derive(Debug, Clone, Copy)]
+pub struct Solver {
+    pub day: u8,
+    pub part_one: SolverFn,
+    pub part_two: SolverFn,
+}
+
+impl Solver {
+    #[must_use]
+    pub const fn new(day: u8, part_one: SolverFn, part_two: SolverFn) -> Self {
+        Self {
+            day,
+            part_one,
+            part_two,
+        }
+    }
+
+    /// Builds a `Solver` that parses the input and runs a part of the default `S`.
+    #[must_use]
+    pub fn from_solution<S: Solution + Default>(day: u8) -> Self {
+        Self::new(day, solve_part_one::<S>, solve_part_two::<S>)
+    }
+
+    /// # Errors
+    /// If the input can't be read or is malformed.
+    pub fn solve(&self, part_two: bool, input: impl AsRef<Path>) -> PuzzleResult<String> {
+        let solver = if part_two {
+            self.part_two
+        } else {
+            self.part_one
+        };
+        solver(input.as_ref())
+    }
+}
+
+fn solve_part_one<S: Solution + Default>(input: &Path) -> PuzzleResult<String> {
+    let puzzle = S::parse(input).map_err(|e| e.with_path(input))?;
+    Ok(S::default().part_one(&puzzle).to_string())
+}
+
+fn solve_part_two<S: Solution + Default>(input: &Path) -> PuzzleResult<String> {
+    let puzzle = S::parse(input).map_err(|e| e.with_path(input))?;
+    Ok(S::default().part_two(&puzzle).to_string())
+}
+
+/// Lookup of every day's `Solver`, keyed by day number.
+#[derive(Debug, Default)]
+pub struct Registry {
+    solvers: BTreeMap<u8, Solver>,
+}
+
+impl Registry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, solver: Solver) -> &mut Self {
+        self.solvers.insert(solver.day, solver);
+        self
+    }
+
+    #[must_use]
+    pub fn get(&self, day: u8) -> Option<&Solver> {
+        self.solvers.get(&day)
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.solvers.keys().copied()
+    }
+}
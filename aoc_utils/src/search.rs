@@ -0,0 +1,490 @@
+//! Searches through graphs given only as a function from each state to its
+//! neighbours, along with the cost of moving to them for weighted searches.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::Coord;
+
+/// Somewhere to keep the cheapest known cost of reaching each state.
+pub trait Costs<S> {
+    fn get(&self, state: &S) -> Option<usize>;
+    fn set(&mut self, state: &S, cost: usize);
+}
+
+impl<S: Clone + Eq + Hash> Costs<S> for HashMap<S, usize> {
+    fn get(&self, state: &S) -> Option<usize> {
+        HashMap::get(self, state).copied()
+    }
+
+    fn set(&mut self, state: &S, cost: usize) {
+        self.insert(state.clone(), cost);
+    }
+}
+
+/// Costs held in one flat array, for states that can be numbered `0..len`.
+#[derive(Debug, Clone)]
+pub struct DenseCosts<F> {
+    costs: Vec<usize>,
+    index: F,
+}
+
+impl<F> DenseCosts<F> {
+    #[must_use]
+    pub fn new(len: usize, index: F) -> Self {
+        Self {
+            costs: vec![usize::MAX; len],
+            index,
+        }
+    }
+}
+
+impl<S, F: Fn(&S) -> usize> Costs<S> for DenseCosts<F> {
+    fn get(&self, state: &S) -> Option<usize> {
+        let cost = self.costs[(self.index)(state)];
+        (cost != usize::MAX).then_some(cost)
+    }
+
+    fn set(&mut self, state: &S, cost: usize) {
+        self.costs[(self.index)(state)] = cost;
+    }
+}
+
+/// The cheapest way found to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    /// Every state from the start to the goal, both included.
+    pub states: Vec<S>,
+    pub cost: usize,
+}
+
+impl<S> Path<S> {
+    /// # Panics
+    /// Never, as a path always holds at least its start.
+    #[must_use]
+    pub fn goal(&self) -> &S {
+        self.states.last().expect("a path always has a start")
+    }
+}
+
+/// A state seen by the search, along with where it was reached from.
+struct Visit<S> {
+    state: S,
+    previous: Option<usize>,
+}
+
+/// A visit waiting in the queue, ordered so the heap pops the lowest priority first.
+struct Queued {
+    priority: usize,
+    cost: usize,
+    visit: usize,
+}
+
+impl PartialEq for Queued {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl Eq for Queued {}
+
+impl Ord for Queued {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl PartialOrd for Queued {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The cheapest path from any of `starts` to a goal state.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar_with(HashMap::new(), starts, neighbours, |_| 0, is_goal)
+}
+
+/// A* search, where `heuristic` must never overestimate the cost left to a goal.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> usize,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar_with(HashMap::new(), starts, neighbours, heuristic, is_goal)
+}
+
+/// A* search recording costs in `costs`, so dense state spaces can skip hashing.
+pub fn astar_with<S, I>(
+    mut costs: impl Costs<S>,
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut visits = vec![];
+    let mut queue = BinaryHeap::new();
+    for state in starts {
+        costs.set(&state, 0);
+        queue.push(Queued {
+            priority: heuristic(&state),
+            cost: 0,
+            visit: visits.len(),
+        });
+        visits.push(Visit {
+            state,
+            previous: None,
+        });
+    }
+
+    while let Some(Queued { cost, visit, .. }) = queue.pop() {
+        let state = &visits[visit].state;
+        if is_goal(state) {
+            return Some(Path {
+                states: trace(visits, visit),
+                cost,
+            });
+        }
+        if costs.get(state).is_some_and(|best| cost > best) {
+            continue;
+        }
+        for (next, step) in neighbours(state) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_some_and(|best| next_cost >= best) {
+                continue;
+            }
+            costs.set(&next, next_cost);
+            queue.push(Queued {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                visit: visits.len(),
+            });
+            visits.push(Visit {
+                state: next,
+                previous: Some(visit),
+            });
+        }
+    }
+    None
+}
+
+/// Walks back from `visit` to its start, returning the states in the order visited.
+fn trace<S>(visits: Vec<Visit<S>>, visit: usize) -> Vec<S> {
+    let mut keep = vec![false; visits.len()];
+    let mut current = Some(visit);
+    while let Some(i) = current {
+        keep[i] = true;
+        current = visits[i].previous;
+    }
+    // Every visit comes after the one it was reached from, so keeping the
+    // original order gives the path from start to goal.
+    visits
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(v, keep)| keep.then_some(v.state))
+        .collect()
+}
+
+/// Everything a search reached on its way through the whole graph.
+#[derive(Debug, Clone)]
+pub struct Reached<S> {
+    /// Every state, in the order it was first settled.
+    pub order: Vec<S>,
+    distances: HashMap<S, usize>,
+    previous: HashMap<S, S>,
+}
+
+impl<S: Clone + Eq + Hash> Reached<S> {
+    fn new() -> Self {
+        Self {
+            order: vec![],
+            distances: HashMap::new(),
+            previous: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn contains(&self, state: &S) -> bool {
+        self.distances.contains_key(state)
+    }
+
+    /// The fewest steps, or lowest cost, to `state`.
+    #[must_use]
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    /// Every reached state along with its distance.
+    pub fn distances(&self) -> impl Iterator<Item = (&S, usize)> {
+        self.distances.iter().map(|(s, d)| (s, *d))
+    }
+
+    /// The state `state` was reached from, or `None` for a start.
+    #[must_use]
+    pub fn previous(&self, state: &S) -> Option<&S> {
+        self.previous.get(state)
+    }
+
+    /// The states from a start to `state`, both included.
+    #[must_use]
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.contains(state) {
+            return None;
+        }
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.previous(path.last()?) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search of every state reachable from `starts`.
+pub fn bfs<S, I>(starts: impl IntoIterator<Item = S>, neighbours: impl FnMut(&S) -> I) -> Reached<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    bfs_within(starts, usize::MAX, neighbours)
+}
+
+/// Breadth-first search of every state no more than `max_steps` from `starts`.
+pub fn bfs_within<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    max_steps: usize,
+    mut neighbours: impl FnMut(&S) -> I,
+) -> Reached<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut reached = Reached::new();
+    let mut queue = VecDeque::new();
+    for state in starts {
+        if !reached.contains(&state) {
+            reached.distances.insert(state.clone(), 0);
+            queue.push_back((state, 0));
+        }
+    }
+
+    while let Some((state, steps)) = queue.pop_front() {
+        if steps < max_steps {
+            for next in neighbours(&state) {
+                if reached.contains(&next) {
+                    continue;
+                }
+                reached.distances.insert(next.clone(), steps + 1);
+                reached.previous.insert(next.clone(), state.clone());
+                queue.push_back((next, steps + 1));
+            }
+        }
+        reached.order.push(state);
+    }
+    reached
+}
+
+/// Cheapest costs to every state reachable from `starts`.
+pub fn dijkstra_all<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+) -> Reached<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut reached = Reached::new();
+    let mut states = vec![];
+    let mut queue = BinaryHeap::new();
+    for state in starts {
+        reached.distances.insert(state.clone(), 0);
+        queue.push(Queued {
+            priority: 0,
+            cost: 0,
+            visit: states.len(),
+        });
+        states.push(state);
+    }
+
+    while let Some(Queued { cost, visit, .. }) = queue.pop() {
+        let state = &states[visit];
+        if reached.distance(state).is_some_and(|best| cost > best) {
+            continue;
+        }
+        let state = state.clone();
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            if reached
+                .distance(&next)
+                .is_some_and(|best| next_cost >= best)
+            {
+                continue;
+            }
+            reached.distances.insert(next.clone(), next_cost);
+            reached.previous.insert(next.clone(), state.clone());
+            queue.push(Queued {
+                priority: next_cost,
+                cost: next_cost,
+                visit: states.len(),
+            });
+            states.push(next);
+        }
+        reached.order.push(state);
+    }
+    reached
+}
+
+/// Every state reachable from `starts`, for when distances don't matter.
+pub fn flood_fill<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+) -> HashSet<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen = HashSet::new();
+    let mut stack: Vec<S> = starts.into_iter().collect();
+    while let Some(state) = stack.pop() {
+        if seen.contains(&state) {
+            continue;
+        }
+        stack.extend(neighbours(&state).into_iter().filter(|n| !seen.contains(n)));
+        seen.insert(state);
+    }
+    seen
+}
+
+/// Handles states first in, first out without remembering which were seen,
+/// for queues where the same state can rightly turn up more than once.
+pub fn walk<S, I>(starts: impl IntoIterator<Item = S>, mut visit: impl FnMut(S) -> I)
+where
+    I: IntoIterator<Item = S>,
+{
+    let mut queue: VecDeque<S> = starts.into_iter().collect();
+    while let Some(state) = queue.pop_front() {
+        queue.extend(visit(state));
+    }
+}
+
+/// The Manhattan distance between two grid coordinates.
+#[must_use]
+pub const fn manhattan(a: Coord, b: Coord) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Edges 0 -> 1 (4), 0 -> 2 (1), 2 -> 1 (2), 1 -> 3 (1), 2 -> 3 (5).
+    fn edges(node: &usize) -> Vec<(usize, usize)> {
+        match node {
+            0 => vec![(1, 4), (2, 1)],
+            1 => vec![(3, 1)],
+            2 => vec![(1, 2), (3, 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn cheapest_path() {
+        let expected = Path {
+            states: vec![0, 2, 1, 3],
+            cost: 4,
+        };
+        assert_eq!(dijkstra([0], edges, |n| *n == 3), Some(expected.clone()));
+        assert_eq!(
+            astar([0], edges, |n| usize::from(*n != 3), |n| *n == 3),
+            Some(expected)
+        );
+        assert_eq!(dijkstra([3], edges, |n| *n == 0), None);
+    }
+
+    #[test]
+    fn dense_costs() {
+        let costs = DenseCosts::new(4, |n: &usize| *n);
+        let found = astar_with(costs, [0], edges, |_| 0, |n| *n == 3);
+        assert_eq!(found.map(|p| (*p.goal(), p.cost)), Some((3, 4)));
+    }
+
+    // A 3x3 grid of cells numbered row by row, with the centre blocked.
+    fn grid(cell: &usize) -> Vec<usize> {
+        let (row, column) = (cell / 3, cell % 3);
+        let mut next = vec![];
+        if row > 0 {
+            next.push(cell - 3);
+        }
+        if row < 2 {
+            next.push(cell + 3);
+        }
+        if column > 0 {
+            next.push(cell - 1);
+        }
+        if column < 2 {
+            next.push(cell + 1);
+        }
+        next.retain(|c| *c != 4);
+        next
+    }
+
+    #[test]
+    fn breadth_first() {
+        let reached = bfs([0], grid);
+        assert_eq!(reached.order.len(), 8);
+        assert_eq!(reached.order[0], 0);
+        assert_eq!(reached.distance(&8), Some(4));
+        assert_eq!(reached.distance(&4), None);
+        let path = reached.path_to(&2).unwrap();
+        assert_eq!(path, [0, 1, 2]);
+
+        let near = bfs_within([0], 2, grid);
+        let mut cells: Vec<_> = near.distances().map(|(c, _)| *c).collect();
+        cells.sort_unstable();
+        assert_eq!(cells, [0, 1, 2, 3, 6]);
+    }
+
+    #[test]
+    fn weighted_distances() {
+        let reached = dijkstra_all([0], edges);
+        assert_eq!(reached.distance(&1), Some(3));
+        assert_eq!(reached.distance(&3), Some(4));
+        assert_eq!(reached.path_to(&3), Some(vec![0, 2, 1, 3]));
+        assert_eq!(reached.order, [0, 2, 1, 3]);
+    }
+
+    #[test]
+    fn fill_and_walk() {
+        assert_eq!(flood_fill([0], grid).len(), 8);
+
+        let mut visited = vec![];
+        walk([1_u32], |n| {
+            visited.push(n);
+            if n < 4 {
+                vec![n * 2, n * 2 + 1]
+            } else {
+                vec![]
+            }
+        });
+        assert_eq!(visited, [1, 2, 3, 4, 5, 6, 7]);
+    }
+}
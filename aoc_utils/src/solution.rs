@@ -0,0 +1,36 @@
+use std::{fmt::Display, io::Read, path::Path};
+
+use crate::{parse_puzzle_file, read_puzzle_from, PuzzleResult};
+
+/// A day's puzzle.
+///
+/// The input is parsed once by `parse_str`, usually through `parse` or
+/// `parse_reader`, and the result shared between both parts. Any constants
+/// that differ between the examples and the real puzzle (step limits,
+/// expansion factors, ...) live on the implementing type.
+pub trait Solution {
+    type Input;
+    type Output: Display;
+
+    /// # Errors
+    /// If the input is malformed.
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input>;
+
+    /// Parses the puzzle in the file at `input`, or stdin if it's `-`.
+    ///
+    /// # Errors
+    /// If the input can't be read or is malformed.
+    fn parse(input: impl AsRef<Path>) -> PuzzleResult<Self::Input> {
+        parse_puzzle_file(input, Self::parse_str)
+    }
+
+    /// # Errors
+    /// If the input can't be read or is malformed.
+    fn parse_reader(reader: impl Read) -> PuzzleResult<Self::Input> {
+        Self::parse_str(&read_puzzle_from(reader)?)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output;
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output;
+}
@@ -0,0 +1,110 @@
+use aho_corasick::AhoCorasick;
+use aoc_utils::{parse_lines, PuzzleError, PuzzleResult, Solution, Solver};
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day01>(1)
+}
+
+#[derive(Debug, Default)]
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+    type Output = u64;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        let patterns = AhoCorasick::new(PATTERNS).unwrap();
+        parse_lines(input, |line| {
+            if patterns.is_match(line) {
+                Ok(line.to_owned())
+            } else {
+                Err(PuzzleError::malformed("expected at least one digit"))
+            }
+        })
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        calibration_value(input, &PATTERNS[..9])
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        calibration_value(input, &PATTERNS)
+    }
+}
+
+const PATTERNS: [&str; 18] = [
+    "1", "2", "3", "4", "5", "6", "7", "8", "9", "one", "two", "three", "four", "five", "six",
+    "seven", "eight", "nine",
+];
+
+/// The sum of each line's first and last digit, read as a two digit number.
+///
+/// A line with no digits in `patterns`, like one with only spelled out digits
+/// in part one, has no calibration value.
+pub fn calibration_value(lines: &[String], patterns: &[&str]) -> u64 {
+    let ac = AhoCorasick::new(patterns).unwrap();
+
+    let mut total = 0;
+    for line in lines {
+        let matches: Vec<&str> = ac
+            .find_overlapping_iter(line)
+            .map(|mat| &line[mat.range()])
+            .collect();
+        total += get_first_last(&matches).unwrap_or(0);
+    }
+    total
+}
+
+fn convert_natural_english_number(input: &str) -> &str {
+    match input {
+        "one" => "1",
+        "two" => "2",
+        "three" => "3",
+        "four" => "4",
+        "five" => "5",
+        "six" => "6",
+        "seven" => "7",
+        "eight" => "8",
+        "nine" => "9",
+        i => i,
+    }
+}
+
+fn get_first_last(input: &[&str]) -> Option<u64> {
+    let mut first = convert_natural_english_number(input.first()?).to_string();
+    let last = convert_natural_english_number(input.last()?);
+
+    first.push_str(last);
+
+    first.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let lines = Day01::parse("test_part1").unwrap();
+        let result = Day01.part_one(&lines);
+        assert_eq!(result, 142);
+    }
+
+    #[test]
+    fn part_two() {
+        let lines = Day01::parse("test_part2").unwrap();
+        let result = Day01.part_two(&lines);
+        assert_eq!(result, 281);
+    }
+
+    #[test]
+    fn lines_without_digits() {
+        let error = Day01::parse_str("1abc2\nabc\n").unwrap_err();
+        assert_eq!(error.line(), Some(2));
+
+        let lines = Day01::parse_str("1abc2\nabcone\n").unwrap();
+        assert_eq!(Day01.part_one(&lines), 12);
+        assert_eq!(Day01.part_two(&lines), 23);
+    }
+}
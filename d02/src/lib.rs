@@ -0,0 +1,182 @@
+use std::str::FromStr;
+
+use aoc_utils::{parse_lines, parse_nom_line, PuzzleResult, Solution, Solver};
+use nom::{
+    bytes::complete::{tag, take_till},
+    character::complete::{alpha1, digit1},
+    combinator::{all_consuming, map_res},
+    error::{Error, ErrorKind},
+    multi::separated_list1,
+    sequence::{delimited, separated_pair},
+    IResult,
+};
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day02>(2)
+}
+
+#[derive(Debug, Default)]
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<GameResult>;
+    type Output = u64;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        parse_lines(input, |line| parse_nom_line(line, GameResult::parse))
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        cube_conundrum(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        cube_conundrum_part2(input)
+    }
+}
+
+#[derive(Debug)]
+struct GameSubset {
+    red: u64,
+    green: u64,
+    blue: u64,
+}
+
+impl GameSubset {
+    const fn new(red: u64, green: u64, blue: u64) -> Self {
+        Self { red, green, blue }
+    }
+
+    fn parse(input: &str) -> IResult<&str, Self> {
+        let (next_input, subset) = take_till(|c| c == ';')(input)?;
+        let fragments: Vec<&str> = subset.split(',').collect();
+
+        let mut red = 0;
+        let mut green = 0;
+        let mut blue = 0;
+        for fragment in fragments {
+            let (_, (num, colour)) = all_consuming(separated_pair(
+                map_res(digit1, u64::from_str),
+                tag(" "),
+                alpha1,
+            ))(fragment.trim())?;
+            match colour {
+                "red" => red = num,
+                "blue" => blue = num,
+                "green" => green = num,
+                _ => return Err(nom::Err::Failure(Error::new(colour, ErrorKind::Tag))),
+            }
+        }
+
+        Ok((next_input, Self::new(red, green, blue)))
+    }
+
+    const fn is_valid(&self, test_case: &Self) -> bool {
+        !(self.red > test_case.red || self.green > test_case.green || self.blue > test_case.blue)
+    }
+
+    const fn power(&self) -> u64 {
+        self.red * self.green * self.blue
+    }
+}
+
+#[derive(Debug)]
+pub struct GameResult {
+    id: u64,
+    subsets: Vec<GameSubset>,
+}
+
+impl GameResult {
+    fn parse(input: &str) -> IResult<&str, Self> {
+        let (next_input, game_id) =
+            delimited(tag("Game "), map_res(digit1, u64::from_str), tag(": "))(input)?;
+        let (next_input, subsets) = separated_list1(tag("; "), GameSubset::parse)(next_input)?;
+
+        Ok((
+            next_input,
+            Self {
+                id: game_id,
+                subsets,
+            },
+        ))
+    }
+
+    fn is_valid(&self, test_case: &GameSubset) -> bool {
+        self.subsets.iter().all(|s| s.is_valid(test_case))
+    }
+
+    fn minimum(&self) -> GameSubset {
+        let red = self.subsets.iter().map(|x| x.red).max().unwrap();
+        let green = self.subsets.iter().map(|x| x.green).max().unwrap();
+        let blue = self.subsets.iter().map(|x| x.blue).max().unwrap();
+
+        GameSubset { red, green, blue }
+    }
+}
+
+pub fn cube_conundrum(conundrum: &[GameResult]) -> u64 {
+    let test_case = GameSubset::new(12, 13, 14);
+
+    conundrum
+        .iter()
+        .filter(|g| g.is_valid(&test_case))
+        .map(|g| g.id)
+        .sum()
+}
+
+pub fn cube_conundrum_part2(conundrum: &[GameResult]) -> u64 {
+    conundrum.iter().map(|g| g.minimum().power()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let conundrum = Day02::parse("test_part1").unwrap();
+        let result = Day02.part_one(&conundrum);
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn part_two() {
+        let conundrum = Day02::parse("test_part1").unwrap();
+        let result = Day02.part_two(&conundrum);
+        assert_eq!(result, 2286);
+    }
+
+    #[test]
+    fn parse_game_subset() {
+        let (_, result) = GameSubset::parse("3 green, 1 blue, 2 red").unwrap();
+
+        assert_eq!(result.blue, 1);
+        assert_eq!(result.red, 2);
+        assert_eq!(result.green, 3);
+    }
+
+    #[test]
+    fn parse_game_result() {
+        let (_, result) =
+            GameResult::parse("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
+
+        assert_eq!(result.id, 1);
+        assert_eq!(result.subsets.len(), 3);
+    }
+
+    #[test]
+    fn parse_unknown_colour() {
+        let error = parse_nom_line("Game 1: 3 blue, 4 purple", GameResult::parse).unwrap_err();
+
+        assert_eq!(error.column(), Some(19));
+    }
+
+    #[test]
+    fn parse_trailing_junk() {
+        let error = parse_nom_line("Game 1: 3 blue, 4 red junk", GameResult::parse).unwrap_err();
+        assert_eq!(error.column(), Some(22));
+        let error = parse_nom_line("Game 1: 3 blue;", GameResult::parse).unwrap_err();
+        assert_eq!(error.column(), Some(15));
+    }
+}
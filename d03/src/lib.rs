@@ -0,0 +1,165 @@
+use aoc_utils::{interval, PuzzleError, PuzzleResult, Solution, Solver};
+use regex::Regex;
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day03>(3)
+}
+
+#[derive(Debug, Default)]
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Output = u64;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        parse_schematic(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        part_number_sum(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        gear_ratio_sum(input)
+    }
+}
+
+/// Reads the schematic's rows, which must be ASCII and all the same width
+/// for the numbers' neighbours to line up.
+fn parse_schematic(input: &str) -> PuzzleResult<Vec<String>> {
+    let rows: Vec<String> = input.lines().map(str::to_owned).collect();
+    let Some(width) = rows.first().map(String::len) else {
+        return Err(PuzzleError::malformed("expected at least one row"));
+    };
+    for (i, row) in rows.iter().enumerate() {
+        if let Some(j) = row.find(|c: char| !c.is_ascii()) {
+            return Err(
+                PuzzleError::unexpected_char(row[j..].chars().next().unwrap_or_default())
+                    .at_column(row[..j].chars().count() + 1)
+                    .at_line(i + 1),
+            );
+        }
+        if row.len() != width {
+            return Err(PuzzleError::malformed(format!(
+                "expected a row of length {width}, found {}",
+                row.len()
+            ))
+            .at_line(i + 1));
+        }
+    }
+    Ok(rows)
+}
+
+pub fn part_number_sum(engine_schema: &[String]) -> u64 {
+    let digits = Regex::new(r"\d+").unwrap();
+    let symbols = Regex::new(r"[^.^\d]").unwrap();
+
+    let puzzle_width = engine_schema[0].len();
+    let puzzle_length = engine_schema.len();
+
+    let mut total = 0;
+    let padding = ".".repeat(puzzle_width);
+
+    for (i, row) in engine_schema.iter().enumerate() {
+        for number_match in digits.find_iter(row) {
+            let number_range = {
+                let start = number_match.start().saturating_sub(1);
+                let end = puzzle_width.min(number_match.end() + 1);
+                start..end
+            };
+            let row_range = {
+                let row_above = i.saturating_sub(1);
+                let row_below = puzzle_length.min(i + 1);
+                row_above..=row_below
+            };
+
+            for j in row_range {
+                let test_row = engine_schema.get(j).unwrap_or(&padding);
+                if symbols
+                    .find_iter(&test_row[number_range.clone()])
+                    .next()
+                    .is_none()
+                {
+                    continue;
+                }
+                let valid_number: u64 = number_match.as_str().parse().unwrap();
+                total += valid_number;
+                break;
+            }
+        }
+    }
+
+    total
+}
+
+pub fn gear_ratio_sum(engine_schema: &[String]) -> u64 {
+    let digits = Regex::new(r"\d+").unwrap();
+    #[allow(clippy::trivial_regex)]
+    let gear = Regex::new(r"[*]").unwrap();
+
+    let puzzle_width = engine_schema[0].len();
+    let puzzle_length = engine_schema.len();
+
+    let mut total = 0;
+    let padding = ".".repeat(puzzle_width);
+
+    for (i, row) in engine_schema.iter().enumerate() {
+        for gear_match in gear.find_iter(row) {
+            let gear_range = gear_match.start().saturating_sub(1)..gear_match.start() + 2;
+
+            let row_range = {
+                let row_above = i.saturating_sub(1);
+                let row_below = puzzle_length.min(i + 1);
+                row_above..=row_below
+            };
+
+            let mut valid_digits: Vec<String> = vec![];
+            for j in row_range {
+                let test_row = engine_schema.get(j).unwrap_or(&padding);
+                for digit_match in digits.find_iter(test_row) {
+                    if interval::overlaps(&digit_match.range(), &gear_range) {
+                        valid_digits.push(digit_match.as_str().to_string());
+                    }
+                }
+            }
+
+            if valid_digits.len() == 2 {
+                let first: u64 = valid_digits.first().unwrap().parse().unwrap();
+                let second: u64 = valid_digits.last().unwrap().parse().unwrap();
+                total += first * second;
+            }
+        }
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let engine_schema = Day03::parse("test_part1").unwrap();
+        let result = Day03.part_one(&engine_schema);
+        assert_eq!(result, 4361);
+    }
+
+    #[test]
+    fn part_two() {
+        let engine_schema = Day03::parse("test_part1").unwrap();
+        let result = Day03.part_two(&engine_schema);
+        assert_eq!(result, 467835);
+    }
+
+    #[test]
+    fn malformed_schematics() {
+        assert!(Day03::parse_str("").is_err());
+        let error = Day03::parse_str("467..\n...*\n").unwrap_err();
+        assert_eq!(error.line(), Some(2));
+        let error = Day03::parse_str("467..\n..é*.\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (Some(2), Some(3)));
+    }
+}
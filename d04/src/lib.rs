@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use aoc_utils::{parse_lines, PuzzleError, PuzzleResult, Solution, Solver};
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day04>(4)
+}
+
+#[derive(Debug, Default)]
+pub struct Day04;
+
+impl Solution for Day04 {
+    /// The number of winning numbers on each card.
+    type Input = Vec<usize>;
+    type Output = u64;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        parse_lines(input, |l| {
+            let Some((_, numbers)) = l.split_once(':') else {
+                return Err(PuzzleError::malformed(
+                    "expected a ':' after the card number",
+                ));
+            };
+            let Some((winning_numbers, our_numbers)) = numbers.split_once('|') else {
+                return Err(PuzzleError::malformed("expected a '|' between the numbers"));
+            };
+            Ok(number_of_matches(&[winning_numbers, our_numbers]))
+        })
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        scratchcards_value(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        total_scratchcards(input)
+    }
+}
+
+fn number_of_matches(numbers: &[&str]) -> usize {
+    let winning_numbers = &numbers.first().unwrap();
+    let our_numbers = &numbers.last().unwrap();
+
+    let mut winning_numbers: HashSet<_> = winning_numbers.split(' ').collect();
+    winning_numbers.remove(&"");
+
+    let mut our_numbers: HashSet<_> = our_numbers.split(' ').collect();
+    our_numbers.remove(&"");
+
+    let common_numbers: HashSet<_> = winning_numbers.intersection(&our_numbers).collect();
+
+    common_numbers.len()
+}
+
+pub fn scratchcards_value(card_matches: &[usize]) -> u64 {
+    card_matches
+        .iter()
+        .filter(|m| **m != 0)
+        .map(|m| u64::pow(2, u32::try_from(m - 1).unwrap()))
+        .sum()
+}
+
+pub fn total_scratchcards(card_matches: &[usize]) -> u64 {
+    let puzzle_length = card_matches.len();
+
+    // Allocate a vector to keep track of the count of each card.
+    let mut card_counts = vec![1; puzzle_length];
+
+    for (card, matches) in card_matches.iter().enumerate() {
+        let current_card_count = { *card_counts.get(card).unwrap() };
+
+        for i in card + 1..=card + matches {
+            if let Some(c) = card_counts.get_mut(i) {
+                *c += current_card_count;
+            }
+        }
+    }
+
+    card_counts.iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let card_matches = Day04::parse("test_part1").unwrap();
+        let result = Day04.part_one(&card_matches);
+        assert_eq!(result, 13);
+    }
+
+    #[test]
+    fn part_two() {
+        let card_matches = Day04::parse("test_part1").unwrap();
+        let result = Day04.part_two(&card_matches);
+        assert_eq!(result, 30);
+    }
+}
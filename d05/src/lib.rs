@@ -0,0 +1,521 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+    ops::Range,
+};
+
+use aoc_utils::{interval::IntervalSet, PuzzleError, PuzzleResult, Solution, Solver};
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day05>(5)
+}
+
+#[derive(Debug, Default)]
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+    type Output = Lowest;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        parse_puzzle(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        Lowest(lowest_seed_location_part1(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        Lowest(lowest_seed_location_part2(input))
+    }
+}
+
+/// The lowest location any seed ends up at, or `None` if the almanac has no
+/// maps leading from seeds to locations or there are no seeds to follow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lowest(pub Option<u64>);
+
+impl fmt::Display for Lowest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(location) => write!(f, "{location}"),
+            None => write!(f, "none, no seed reaches a location"),
+        }
+    }
+}
+
+/// One line of a map, moving every value in `source` by the same offset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MapRange {
+    source: Range<u64>,
+    destination_start: u64,
+}
+
+impl MapRange {
+    const fn shift(&self, value: u64) -> u64 {
+        value - self.source.start + self.destination_start
+    }
+
+    const fn destination(&self) -> Range<u64> {
+        self.destination_start..self.shift(self.source.end - 1) + 1
+    }
+}
+
+/// A single almanac map. Values outside every range map to themselves.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    /// Sorted by source start and never overlapping.
+    ranges: Vec<MapRange>,
+}
+
+impl RangeMap {
+    fn new(mut ranges: Vec<MapRange>) -> Self {
+        ranges.sort_by_key(|r| r.source.start);
+        Self { ranges }
+    }
+
+    #[must_use]
+    pub fn map(&self, value: u64) -> u64 {
+        self.ranges
+            .iter()
+            .find(|r| r.source.contains(&value))
+            .map_or(value, |r| r.shift(value))
+    }
+
+    /// Maps every value in `values`, splitting ranges wherever they cross
+    /// into a different line of the map.
+    #[must_use]
+    pub fn map_set(&self, values: &IntervalSet<u64>) -> IntervalSet<u64> {
+        let mut unmapped = values.clone();
+        let mut mapped = IntervalSet::new();
+        for r in &self.ranges {
+            let source = IntervalSet::from(r.source.clone());
+            let hit = unmapped.intersection(&source);
+            mapped = mapped.union(&hit.shift(r.source.start, r.destination_start));
+            unmapped = unmapped.difference(&source);
+        }
+        mapped.union(&unmapped)
+    }
+
+    /// Every value that maps into `values`.
+    #[must_use]
+    pub fn inverse_set(&self, values: &IntervalSet<u64>) -> IntervalSet<u64> {
+        let mut preimage = IntervalSet::new();
+        let mut sources = IntervalSet::new();
+        for r in &self.ranges {
+            let hit = values.intersection(&IntervalSet::from(r.destination()));
+            preimage = preimage.union(&hit.shift(r.destination_start, r.source.start));
+            sources.insert(r.source.clone());
+        }
+        preimage.union(&values.difference(&sources))
+    }
+
+    /// Every line of the map plus the gaps between them, which map to
+    /// themselves, covering every value.
+    fn pieces(&self) -> Vec<MapRange> {
+        let mut pieces = vec![];
+        let mut start = 0;
+        for r in &self.ranges {
+            if start < r.source.start {
+                pieces.push(MapRange {
+                    source: start..r.source.start,
+                    destination_start: start,
+                });
+            }
+            pieces.push(r.clone());
+            start = r.source.end;
+        }
+        if start < u64::MAX {
+            pieces.push(MapRange {
+                source: start..u64::MAX,
+                destination_start: start,
+            });
+        }
+        pieces
+    }
+
+    /// The single map equivalent to applying this map and then `next`.
+    #[must_use]
+    pub fn then(&self, next: &Self) -> Self {
+        let next_pieces = next.pieces();
+        let mut ranges: Vec<MapRange> = vec![];
+        for piece in self.pieces() {
+            let image = piece.destination();
+            for next_piece in &next_pieces {
+                let start = image.start.max(next_piece.source.start);
+                let end = image.end.min(next_piece.source.end);
+                if start >= end {
+                    continue;
+                }
+                let source_start = piece.source.start + (start - image.start);
+                let range = MapRange {
+                    source: source_start..source_start + (end - start),
+                    destination_start: next_piece.shift(start),
+                };
+                match ranges.last_mut() {
+                    Some(last)
+                        if last.source.end == range.source.start
+                            && last.destination().end == range.destination_start =>
+                    {
+                        last.source.end = range.source.end;
+                    }
+                    _ => ranges.push(range),
+                }
+            }
+        }
+        ranges.retain(|r| r.source.start != r.destination_start);
+        Self::new(ranges)
+    }
+}
+
+/// Written out the way the almanac lists it, one line per range.
+impl fmt::Display for RangeMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for r in &self.ranges {
+            writeln!(
+                f,
+                "{} {} {}",
+                r.destination_start,
+                r.source.start,
+                r.source.end - r.source.start
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A map from one category, like `seed`, to another, like `soil`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryMap {
+    pub source: String,
+    pub target: String,
+    pub map: RangeMap,
+}
+
+impl fmt::Display for CategoryMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}-to-{} map:", self.source, self.target)?;
+        write!(f, "{}", self.map)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<CategoryMap>,
+}
+
+impl Almanac {
+    #[must_use]
+    pub fn seeds(&self) -> &[u64] {
+        &self.seeds
+    }
+
+    /// The maps leading from `source` to `target`, if the almanac links them.
+    #[must_use]
+    pub fn chain(&self, source: &str, target: &str) -> Option<Chain<'_>> {
+        let mut previous: HashMap<&str, &CategoryMap> = HashMap::new();
+        let mut queue = VecDeque::from([source]);
+        while let Some(category) = queue.pop_front() {
+            if category == target {
+                break;
+            }
+            for map in self.maps.iter().filter(|m| m.source == category) {
+                if map.target != source && !previous.contains_key(map.target.as_str()) {
+                    previous.insert(&map.target, map);
+                    queue.push_back(&map.target);
+                }
+            }
+        }
+
+        let mut maps = vec![];
+        let mut category = target;
+        while category != source {
+            let map = previous.get(category)?;
+            maps.push(&map.map);
+            category = &map.source;
+        }
+        maps.reverse();
+
+        Some(Chain {
+            source: source.to_owned(),
+            target: target.to_owned(),
+            maps,
+        })
+    }
+}
+
+/// A run of maps applied one after another.
+#[derive(Debug, Clone)]
+pub struct Chain<'a> {
+    source: String,
+    target: String,
+    maps: Vec<&'a RangeMap>,
+}
+
+impl Chain<'_> {
+    #[must_use]
+    pub fn map(&self, value: u64) -> u64 {
+        self.maps.iter().fold(value, |value, map| map.map(value))
+    }
+
+    #[must_use]
+    pub fn map_set(&self, values: &IntervalSet<u64>) -> IntervalSet<u64> {
+        self.maps
+            .iter()
+            .fold(values.clone(), |values, map| map.map_set(&values))
+    }
+
+    /// Every source value that ends up at `value`.
+    #[must_use]
+    pub fn inverse(&self, value: u64) -> IntervalSet<u64> {
+        self.inverse_set(&IntervalSet::from(value..value + 1))
+    }
+
+    /// Every source value that ends up in `values`.
+    #[must_use]
+    pub fn inverse_set(&self, values: &IntervalSet<u64>) -> IntervalSet<u64> {
+        self.maps
+            .iter()
+            .rev()
+            .fold(values.clone(), |values, map| map.inverse_set(&values))
+    }
+
+    /// The whole chain collapsed into a single map.
+    #[must_use]
+    pub fn compose(&self) -> CategoryMap {
+        CategoryMap {
+            source: self.source.clone(),
+            target: self.target.clone(),
+            map: self
+                .maps
+                .iter()
+                .fold(RangeMap::default(), |composed, map| composed.then(map)),
+        }
+    }
+}
+
+/// A line of a map along with its line number, to point at overlaps.
+type NumberedRange = (usize, MapRange);
+
+fn parse_puzzle(input: &str) -> PuzzleResult<Almanac> {
+    let mut lines = input.lines().enumerate();
+
+    let seeds = match lines.next().and_then(|(_, l)| l.strip_prefix("seeds:")) {
+        Some(seeds) => parse_numbers(seeds).map_err(|e| e.at_line(1))?,
+        None => return Err(PuzzleError::malformed("expected the seeds first").at_line(1)),
+    };
+    // Part two reads the seeds as pairs of start and length.
+    if seeds.len() % 2 != 0 {
+        return Err(PuzzleError::malformed("expected the seeds in pairs").at_line(1));
+    }
+    if seeds
+        .chunks_exact(2)
+        .any(|pair| pair[0].checked_add(pair[1]).is_none())
+    {
+        return Err(PuzzleError::malformed("seed range runs past the largest value").at_line(1));
+    }
+
+    let mut maps: Vec<(String, String, Vec<NumberedRange>)> = vec![];
+    for (i, line) in lines {
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(header) = line.strip_suffix(" map:") {
+            let Some((source, target)) = header.split_once("-to-") else {
+                return Err(PuzzleError::malformed(format!(
+                    "expected a header like seed-to-soil, got {header:?}"
+                ))
+                .at_line(i + 1));
+            };
+            maps.push((source.to_owned(), target.to_owned(), vec![]));
+            continue;
+        }
+        let Some((_, _, map)) = maps.last_mut() else {
+            return Err(PuzzleError::malformed("expected a map header").at_line(i + 1));
+        };
+        let numbers = parse_numbers(line).map_err(|e| e.at_line(i + 1))?;
+        let [destination_start, source_start, length] = numbers[..] else {
+            return Err(PuzzleError::malformed(
+                "expected destination start, source start and length",
+            )
+            .at_line(i + 1));
+        };
+        if length == 0 {
+            return Err(
+                PuzzleError::malformed("expected a range of at least one value").at_line(i + 1),
+            );
+        }
+        let (Some(source_end), Some(_)) = (
+            source_start.checked_add(length),
+            destination_start.checked_add(length),
+        ) else {
+            return Err(PuzzleError::malformed("range runs past the largest value").at_line(i + 1));
+        };
+        map.push((
+            i + 1,
+            MapRange {
+                source: source_start..source_end,
+                destination_start,
+            },
+        ));
+    }
+
+    let maps = maps
+        .into_iter()
+        .map(|(source, target, mut ranges)| {
+            ranges.sort_by_key(|(_, r)| r.source.start);
+            if let Some([(_, a), (line, b)]) = ranges
+                .array_windows()
+                .find(|[(_, a), (_, b)]| a.source.end > b.source.start)
+            {
+                return Err(PuzzleError::malformed(format!(
+                    "source range {:?} overlaps {:?}",
+                    b.source, a.source
+                ))
+                .at_line(*line));
+            }
+            Ok(CategoryMap {
+                source,
+                target,
+                map: RangeMap::new(ranges.into_iter().map(|(_, r)| r).collect()),
+            })
+        })
+        .collect::<PuzzleResult<_>>()?;
+    Ok(Almanac { seeds, maps })
+}
+
+fn parse_numbers(input: &str) -> PuzzleResult<Vec<u64>> {
+    input
+        .split_ascii_whitespace()
+        .map(|n| Ok(n.parse()?))
+        .collect()
+}
+
+pub fn lowest_seed_location_part1(almanac: &Almanac) -> Option<u64> {
+    let chain = almanac.chain("seed", "location")?;
+    almanac.seeds.iter().map(|&seed| chain.map(seed)).min()
+}
+
+pub fn lowest_seed_location_part2(almanac: &Almanac) -> Option<u64> {
+    let seeds: IntervalSet<u64> = almanac
+        .seeds
+        .chunks_exact(2)
+        .map(|pair| pair[0]..pair[0] + pair[1])
+        .collect();
+
+    let chain = almanac.chain("seed", "location")?;
+    chain.map_set(&seeds).min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let almanac = Day05::parse("test_part1").unwrap();
+        let result = Day05.part_one(&almanac);
+        assert_eq!(result, Lowest(Some(35)));
+    }
+
+    #[test]
+    fn part_two() {
+        let almanac = Day05::parse("test_part1").unwrap();
+        let result = Day05.part_two(&almanac);
+        assert_eq!(result, Lowest(Some(46)));
+    }
+
+    #[test]
+    fn map_set_splits_on_overlaps() {
+        let map = RangeMap::new(vec![
+            MapRange {
+                source: 10..20,
+                destination_start: 100,
+            },
+            MapRange {
+                source: 25..30,
+                destination_start: 0,
+            },
+        ]);
+        let mapped = map.map_set(&IntervalSet::from(5..27));
+        assert_eq!(mapped.ranges(), &[0..2, 5..10, 20..25, 100..110]);
+        let mapped = map.map_set(&IntervalSet::from(30..40));
+        assert_eq!(mapped, IntervalSet::from(30..40));
+    }
+
+    #[test]
+    fn chain_between_any_categories() {
+        let almanac = Day05::parse("test_part1").unwrap();
+        let chain = almanac.chain("soil", "humidity").unwrap();
+        // Soil 14 is fertilizer 53, water 49, light 42, temperature 42, humidity 43.
+        assert_eq!(chain.map(14), 43);
+        assert!(almanac.chain("location", "seed").is_none());
+        assert_eq!(almanac.chain("water", "water").unwrap().map(7), 7);
+    }
+
+    #[test]
+    fn inverse_mapping() {
+        let almanac = Day05::parse("test_part1").unwrap();
+        let chain = almanac.chain("seed", "location").unwrap();
+        assert_eq!(chain.inverse(82), IntervalSet::from(79..80));
+        for location in 0..120 {
+            let seeds = chain.inverse(location);
+            for range in seeds.ranges() {
+                assert_eq!(chain.map(range.start), location);
+                assert_eq!(chain.map(range.end - 1), location);
+            }
+        }
+    }
+
+    #[test]
+    fn composed_map_matches_chain() {
+        let almanac = Day05::parse("test_part1").unwrap();
+        let chain = almanac.chain("seed", "location").unwrap();
+        let composed = chain.compose();
+        for seed in 0..200 {
+            assert_eq!(composed.map.map(seed), chain.map(seed), "seed {seed}");
+        }
+
+        let seed_to_soil = almanac.chain("seed", "soil").unwrap().compose();
+        assert_eq!(
+            seed_to_soil.to_string(),
+            "seed-to-soil map:\n52 50 48\n50 98 2\n"
+        );
+    }
+
+    #[test]
+    fn empty_ranges() {
+        let error = Day05::parse_str("seeds: 1 1\n\nseed-to-location map:\n5 1 0\n").unwrap_err();
+        assert_eq!(error.line(), Some(4));
+    }
+
+    #[test]
+    fn overlapping_ranges() {
+        let error =
+            Day05::parse_str("seeds: 1 1\n\nseed-to-location map:\n50 10 5\n0 1 10\n9 20 1\n")
+                .unwrap_err();
+        assert_eq!(error.line(), Some(4));
+    }
+
+    #[test]
+    fn missing_seed_to_location_maps() {
+        let almanac = Day05::parse_str("seeds: 1 1\n\nseed-to-soil map:\n5 1 2\n").unwrap();
+        assert_eq!(Day05.part_one(&almanac), Lowest(None));
+        assert_eq!(almanac.chain("seed", "soil").unwrap().map(1), 5);
+    }
+
+    #[test]
+    fn seed_pairs() {
+        let maps = "\n\nseed-to-location map:\n5 1 2\n";
+        let error = Day05::parse_str(&format!("seeds: 1 2 3{maps}")).unwrap_err();
+        assert_eq!(error.line(), Some(1));
+        let error = Day05::parse_str(&format!("seeds: 18446744073709551610 10{maps}")).unwrap_err();
+        assert_eq!(error.line(), Some(1));
+
+        let almanac = Day05::parse_str(&format!("seeds:{maps}")).unwrap();
+        assert_eq!(Day05.part_one(&almanac), Lowest(None));
+        assert_eq!(Day05.part_two(&almanac), Lowest(None));
+    }
+}
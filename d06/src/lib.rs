@@ -0,0 +1,233 @@
+use std::fmt;
+
+use aoc_utils::{PuzzleError, PuzzleResult, Solution, Solver};
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day06>(6)
+}
+
+#[derive(Debug, Default)]
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Races;
+    type Output = Ways;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        parse_puzzle(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        Ways(Some(race_records(&input.times, &input.distances)))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        Ways(
+            input
+                .time
+                .zip(input.distance)
+                .map(|(t, d)| number_winning_times(t, d)),
+        )
+    }
+}
+
+/// The sheet of paper, read both as separate races and as one long race.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Races {
+    pub times: Vec<u64>,
+    pub distances: Vec<u64>,
+    /// The single race's time, with the bad kerning undone, or `None` if
+    /// it's too large to race.
+    pub time: Option<u64>,
+    /// The single race's record distance, with the bad kerning undone, or
+    /// `None` if it's too large to race.
+    pub distance: Option<u64>,
+}
+
+/// The number of ways to beat the records, or `None` if the joined race is
+/// too large.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ways(pub Option<u64>);
+
+impl fmt::Display for Ways {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(ways) => write!(f, "{ways}"),
+            None => write!(f, "none, the joined race is too large for a u64"),
+        }
+    }
+}
+
+fn parse_puzzle(input: &str) -> PuzzleResult<Races> {
+    let puzzle: Vec<&str> = input.lines().collect();
+    let [time, distance] = puzzle.as_slice() else {
+        return Err(PuzzleError::malformed(
+            "expected a time line and a distance line",
+        ));
+    };
+
+    let (times, time) = parse_line(time).map_err(|e| e.at_line(1))?;
+    let (distances, distance) = parse_line(distance).map_err(|e| e.at_line(2))?;
+    if times.len() != distances.len() {
+        return Err(PuzzleError::malformed(
+            "expected as many distances as times",
+        ));
+    }
+
+    Ok(Races {
+        times,
+        distances,
+        time,
+        distance,
+    })
+}
+
+/// The numbers on a line, and the single number they make when the bad
+/// kerning is undone by joining all their digits, if it fits.
+fn parse_line(puzzle_line: &str) -> PuzzleResult<(Vec<u64>, Option<u64>)> {
+    let Some((_, numbers)) = puzzle_line.split_once(':') else {
+        return Err(PuzzleError::malformed("expected a ':' after the label"));
+    };
+
+    let separate: Vec<u64> = numbers
+        .split_ascii_whitespace()
+        .map(|n| Ok(n.parse()?))
+        .collect::<PuzzleResult<_>>()?;
+    if separate.is_empty() {
+        return Err(PuzzleError::malformed("expected at least one number"));
+    }
+    let joined: String = numbers.split_ascii_whitespace().collect();
+    let joined = joined.parse().ok();
+
+    Ok((separate, joined))
+}
+
+/// How many whole milliseconds of holding the button beat the record.
+///
+/// Holding for `h` travels `h * (time - h)`, which is symmetric about
+/// `time / 2`, so only the first winning hold is needed. It's near the lower
+/// root of `h² - time·h + distance`, found with an integer square root and
+/// nudged onto the exact boundary, as draws don't count.
+fn number_winning_times(time: u64, distance: u64) -> u64 {
+    let (time, distance) = (u128::from(time), u128::from(distance));
+    let beats = |hold: u128| hold * (time - hold) > distance;
+
+    if !beats(time / 2) {
+        return 0;
+    }
+
+    let root = (time * time - 4 * distance).isqrt();
+    let mut first = (time - root) / 2;
+    while !beats(first) {
+        first += 1;
+    }
+    while first > 0 && beats(first - 1) {
+        first -= 1;
+    }
+
+    u64::try_from(time + 1 - 2 * first).expect("fewer winning holds than the race time")
+}
+
+pub fn race_records(time: &[u64], distance: &[u64]) -> u64 {
+    time.iter()
+        .zip(distance)
+        .map(|(t, d)| number_winning_times(*t, *d))
+        .product()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let puzzle = Day06::parse("test_part1").unwrap();
+        let result = Day06.part_one(&puzzle);
+        assert_eq!(result, Ways(Some(288)));
+    }
+
+    #[test]
+    fn part_two() {
+        let puzzle = Day06::parse("test_part1").unwrap();
+        let result = Day06.part_two(&puzzle);
+        assert_eq!(result, Ways(Some(71503)));
+    }
+
+    #[test]
+    fn parse_from_reader() {
+        let puzzle = Day06::parse_reader(File::open("test_part1").unwrap()).unwrap();
+        assert_eq!(Day06.part_one(&puzzle), Ways(Some(288)));
+    }
+
+    fn brute_force(time: u64, distance: u64) -> u64 {
+        (0..=time).filter(|h| h * (time - h) > distance).count() as u64
+    }
+
+    /// A xorshift generator, so the random races are the same on every run.
+    fn races(count: usize) -> impl Iterator<Item = (u64, u64)> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        (0..count).map(move |_| {
+            let time = next() % 2000;
+            // Reach just past the furthest possible distance to cover losing races.
+            let distance = next() % (time * time / 4 + 2);
+            (time, distance)
+        })
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        for (time, distance) in races(2000) {
+            assert_eq!(
+                number_winning_times(time, distance),
+                brute_force(time, distance),
+                "time {time}, distance {distance}"
+            );
+        }
+    }
+
+    #[test]
+    fn draws_and_lost_races() {
+        // Holding for 3 or 7 exactly matches the record of 21.
+        assert_eq!(number_winning_times(10, 21), 3);
+        assert_eq!(number_winning_times(10, 25), 0);
+        assert_eq!(number_winning_times(0, 0), 0);
+        assert_eq!(number_winning_times(1, 0), 0);
+    }
+
+    #[test]
+    fn exact_for_huge_races() {
+        // Far beyond where an f64 can hold the squared time exactly.
+        for (time, distance) in [
+            (u64::MAX, u64::MAX),
+            (u64::MAX - 1, 0),
+            (3_000_000_001, 2_250_000_000_000_000_000),
+            (4_294_967_297, 4_611_686_020_574_871_551),
+        ] {
+            let count = number_winning_times(time, distance);
+            let first = (u128::from(time) + 1 - u128::from(count)) / 2;
+            let travelled = |h: u128| h * (u128::from(time) - h);
+            assert!(travelled(first) > u128::from(distance), "time {time}");
+            assert!(travelled(first - 1) <= u128::from(distance), "time {time}");
+        }
+    }
+
+    #[test]
+    fn joined_numbers_too_large() {
+        let puzzle = Day06::parse_str("Time: 4294967296 4294967296\nDistance: 1 2\n").unwrap();
+        assert_eq!(puzzle.time, None);
+        assert_eq!(
+            Day06.part_one(&puzzle),
+            Ways(Some(4_294_967_295 * 4_294_967_295))
+        );
+        assert_eq!(Day06.part_two(&puzzle), Ways(None));
+    }
+}
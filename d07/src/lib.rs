@@ -0,0 +1,408 @@
+use std::{fmt, str::FromStr};
+
+use aoc_utils::{parse_lines, PuzzleError, PuzzleResult, Solution, Solver};
+use clap::ValueEnum;
+use counter::Counter;
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day07>(7)
+}
+
+#[derive(Debug, Default)]
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Hands;
+    type Output = Winnings;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        parse_hands(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        Winnings(total_winnings(input, &Ruleset::Jacks.into()))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        Winnings(total_winnings(input, &Ruleset::Jokers.into()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Card {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl FromStr for Card {
+    type Err = PuzzleError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "2" => Ok(Self::Two),
+            "3" => Ok(Self::Three),
+            "4" => Ok(Self::Four),
+            "5" => Ok(Self::Five),
+            "6" => Ok(Self::Six),
+            "7" => Ok(Self::Seven),
+            "8" => Ok(Self::Eight),
+            "9" => Ok(Self::Nine),
+            "T" => Ok(Self::Ten),
+            "J" => Ok(Self::Jack),
+            "Q" => Ok(Self::Queen),
+            "K" => Ok(Self::King),
+            "A" => Ok(Self::Ace),
+            _ => Err(PuzzleError::malformed(format!(
+                "unrecognised card {input:?}"
+            ))),
+        }
+    }
+}
+
+const CARDS: &str = "23456789TJQKA";
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", &CARDS[*self as usize..=*self as usize])
+    }
+}
+
+/// Which way to read the `J` cards, applied by turning it into an `Evaluator`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ruleset {
+    /// `J` is a Jack, ranked between Ten and Queen.
+    Jacks,
+    /// `J` is a Joker, ranked below Two but standing in for any card when
+    /// deciding the hand's type.
+    Jokers,
+}
+
+impl From<Ruleset> for Evaluator {
+    fn from(ruleset: Ruleset) -> Self {
+        let wild = match ruleset {
+            Ruleset::Jacks => vec![],
+            Ruleset::Jokers => vec![Card::Jack],
+        };
+        Self {
+            wild,
+            hand_size: 5,
+            tiebreak: Tiebreak::Position,
+        }
+    }
+}
+
+/// How hands of the same type are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Tiebreak {
+    /// Compare the first cards, then the second, and so on, as in the puzzle.
+    Position,
+    /// Compare the strongest cards, then the next strongest, and so on.
+    Sorted,
+}
+
+/// Decides each hand's type and how it ranks against the others.
+///
+/// This, rather than card parsing, is where a `Ruleset` takes effect: `J`
+/// always parses as `Card::Jack`, and only the evaluator decides whether it's
+/// wild. The hands are parsed once and shared between both parts, and any card
+/// can be made wild, so the cards themselves don't depend on the rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Evaluator {
+    /// Cards that stand in for any other when deciding a hand's type, and
+    /// rank below every card that isn't wild.
+    pub wild: Vec<Card>,
+    /// How many cards make up a hand.
+    pub hand_size: usize,
+    pub tiebreak: Tiebreak,
+}
+
+impl Evaluator {
+    fn is_wild(&self, card: Card) -> bool {
+        self.wild.contains(&card)
+    }
+
+    /// How strong `card` is when breaking ties between hands of the same type.
+    fn strength(&self, card: Card) -> u8 {
+        if self.is_wild(card) {
+            card as u8
+        } else {
+            card as u8 + CARDS.len() as u8
+        }
+    }
+
+    #[must_use]
+    pub fn hand_type(&self, cards: &[Card]) -> HandType {
+        let counter = cards
+            .iter()
+            .filter(|c| !self.is_wild(**c))
+            .collect::<Counter<_>>();
+        let wild = cards.len() - counter.total::<usize>();
+
+        let mut counts: Vec<usize> = counter.most_common().into_iter().map(|(_, n)| n).collect();
+        match counts.first_mut() {
+            Some(n) => *n += wild,
+            None => counts.push(wild),
+        }
+
+        match counts[..] {
+            [n, ..] if n >= 5 => HandType::FiveKind,
+            [4, ..] => HandType::FourKind,
+            [3, n, ..] if n >= 2 => HandType::FullHouse,
+            [3, ..] => HandType::ThreeKind,
+            [2, 2, ..] => HandType::TwoPair,
+            [2, ..] => HandType::OnePair,
+            _ => HandType::High,
+        }
+    }
+
+    /// What hands are ordered by: their type, then their cards' strengths.
+    fn sort_key(&self, hand: &Hand) -> (HandType, Vec<u8>) {
+        let mut strengths: Vec<u8> = hand.cards.iter().map(|c| self.strength(*c)).collect();
+        if self.tiebreak == Tiebreak::Sorted {
+            strengths.sort_unstable_by(|a, b| b.cmp(a));
+        }
+        (self.hand_type(&hand.cards), strengths)
+    }
+
+    /// Every hand with its type and rank, weakest first.
+    ///
+    /// # Errors
+    /// If any hand doesn't have `hand_size` cards.
+    pub fn rank(&self, hands: &[Hand]) -> PuzzleResult<Vec<RankedHand>> {
+        if let Some(i) = hands.iter().position(|h| h.cards.len() != self.hand_size) {
+            return Err(PuzzleError::malformed(format!(
+                "expected {} cards, found {}",
+                self.hand_size,
+                hands[i].cards.len()
+            ))
+            .at_line(i + 1));
+        }
+
+        let mut hands = hands.to_vec();
+        hands.sort_by_cached_key(|h| self.sort_key(h));
+
+        let ranked = hands
+            .into_iter()
+            .enumerate()
+            .map(|(i, hand)| RankedHand {
+                hand_type: self.hand_type(&hand.cards),
+                rank: i + 1,
+                hand,
+            })
+            .collect();
+        Ok(ranked)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HandType {
+    High,
+    OnePair,
+    TwoPair,
+    ThreeKind,
+    FullHouse,
+    FourKind,
+    FiveKind,
+}
+
+impl fmt::Display for HandType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::High => "high card",
+            Self::OnePair => "one pair",
+            Self::TwoPair => "two pair",
+            Self::ThreeKind => "three of a kind",
+            Self::FullHouse => "full house",
+            Self::FourKind => "four of a kind",
+            Self::FiveKind => "five of a kind",
+        };
+        f.pad(name)
+    }
+}
+
+/// A hand along with where it placed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RankedHand {
+    pub hand: Hand,
+    pub hand_type: HandType,
+    pub rank: usize,
+}
+
+impl RankedHand {
+    #[must_use]
+    pub const fn winnings(&self) -> usize {
+        self.rank * self.hand.bid
+    }
+}
+
+impl fmt::Display for RankedHand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:>5} ", self.rank)?;
+        for card in &self.hand.cards {
+            write!(f, "{card}")?;
+        }
+        write!(f, " {:<15} {}", self.hand_type, self.hand.bid)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    cards: Vec<Card>,
+    bid: usize,
+}
+
+impl FromStr for Hand {
+    type Err = PuzzleError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let Some((cards, bid)) = input.split_once(' ') else {
+            return Err(PuzzleError::malformed("expected cards and a bid"));
+        };
+
+        let cards: Vec<Card> = cards
+            .char_indices()
+            .map(|(i, c)| Card::from_str(&c.to_string()).map_err(|e| e.at_column(i + 1)))
+            .collect::<PuzzleResult<_>>()?;
+        let bid: usize = bid
+            .trim()
+            .parse()
+            .map_err(|e| PuzzleError::from(e).at_column(input.len() - bid.len() + 1))?;
+
+        Ok(Self { cards, bid })
+    }
+}
+pub type Hands = Vec<Hand>;
+
+fn parse_hands(input: &str) -> PuzzleResult<Hands> {
+    parse_lines(input, Hand::from_str)
+}
+
+/// The total winnings, or why the hands couldn't be ranked.
+#[derive(Debug)]
+pub struct Winnings(pub PuzzleResult<usize>);
+
+impl fmt::Display for Winnings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Ok(winnings) => write!(f, "{winnings}"),
+            Err(e) => write!(f, "none, {e}"),
+        }
+    }
+}
+
+/// # Errors
+/// If any hand is the wrong size for `evaluator`.
+pub fn total_winnings(hands: &[Hand], evaluator: &Evaluator) -> PuzzleResult<usize> {
+    Ok(evaluator
+        .rank(hands)?
+        .iter()
+        .map(RankedHand::winnings)
+        .sum())
+}
+
+/// One line per hand, weakest first, giving its rank, cards, type and bid.
+#[must_use]
+pub fn report(ranked: &[RankedHand]) -> String {
+    ranked.iter().map(|r| format!("{r}\n")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let hands = Day07::parse("test_part1").unwrap();
+        let result = Day07.part_one(&hands);
+        assert_eq!(result.0.unwrap(), 6440);
+    }
+
+    #[test]
+    fn part_two() {
+        let hands = Day07::parse("test_part1").unwrap();
+        let result = Day07.part_two(&hands);
+        assert_eq!(result.0.unwrap(), 5905);
+    }
+
+    #[test]
+    fn wrong_hand_size() {
+        let hands = Day07::parse_str("32T3K 765\nT55J 684\n").unwrap();
+        let result = Day07.part_one(&hands);
+        assert_eq!(result.0.unwrap_err().line(), Some(2));
+        assert_eq!(
+            Day07.part_two(&hands).to_string(),
+            "none, 2: expected 5 cards, found 4"
+        );
+    }
+
+    #[test]
+    fn parse_bad_card() {
+        let error = Hand::from_str("32X3K 765").unwrap_err();
+        assert_eq!(error.column(), Some(3));
+    }
+
+    fn cards(hand: &str) -> Vec<Card> {
+        hand.chars()
+            .map(|c| c.to_string().parse().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn jokers_are_wild_but_weak() {
+        let jacks = Evaluator::from(Ruleset::Jacks);
+        let jokers = Evaluator::from(Ruleset::Jokers);
+        assert_eq!(jacks.hand_type(&cards("JKKK2")), HandType::ThreeKind);
+        assert_eq!(jokers.hand_type(&cards("JKKK2")), HandType::FourKind);
+        assert_eq!(jokers.hand_type(&cards("JJJJJ")), HandType::FiveKind);
+        assert!(jokers.strength(Card::Jack) < jokers.strength(Card::Two));
+        assert!(jacks.strength(Card::Jack) > jacks.strength(Card::Ten));
+    }
+
+    #[test]
+    fn house_variants() {
+        let evaluator = Evaluator {
+            wild: vec![Card::Two, Card::Jack],
+            hand_size: 3,
+            tiebreak: Tiebreak::Sorted,
+        };
+        assert_eq!(evaluator.hand_type(&cards("2JA")), HandType::ThreeKind);
+        assert_eq!(evaluator.hand_type(&cards("2KA")), HandType::OnePair);
+        assert_eq!(evaluator.hand_type(&cards("TKA")), HandType::High);
+
+        let hands = ["AK3 1", "3KA 2", "QQ2 3"].map(|h| h.parse().unwrap());
+        let ranked = evaluator.rank(&hands).unwrap();
+        // Sorted, AK3 and 3KA are the same cards, so they keep their order.
+        let bids: Vec<_> = ranked.iter().map(|r| r.hand.bid).collect();
+        assert_eq!(bids, [1, 2, 3]);
+
+        let error = Evaluator::from(Ruleset::Jacks).rank(&hands).unwrap_err();
+        assert_eq!(error.line(), Some(1));
+    }
+
+    #[test]
+    fn ranked_report() {
+        let hands = Day07::parse("test_part1").unwrap();
+        let ranked = Evaluator::from(Ruleset::Jokers).rank(&hands).unwrap();
+        assert_eq!(
+            report(&ranked),
+            "    1 32T3K one pair        765
+    2 KK677 two pair        28
+    3 T55J5 four of a kind  684
+    4 QQQJA four of a kind  483
+    5 KTJJT four of a kind  220
+"
+        );
+    }
+}
@@ -0,0 +1,417 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use aoc_utils::{
+    cycle::{self, Cycle},
+    PuzzleError, PuzzleResult, Solution, Solver,
+};
+use num::integer::{lcm, ExtendedGcd, Integer};
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day08>(8)
+}
+
+#[derive(Debug, Default)]
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = (Vec<bool>, Network);
+    type Output = StepCount;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        parse_puzzle(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        let (instructions, network) = input;
+        StepCount(step_count(instructions, network))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        let (instructions, network) = input;
+        StepCount(simultaneous_step_count(instructions, network))
+    }
+}
+
+pub type Network = HashMap<String, (String, String)>;
+
+/// Why a walk never gets where it's going.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WalkError {
+    /// The walk reached a node the network doesn't list.
+    MissingNode(String),
+    /// The walk from `start` came back to a node at the same point in the
+    /// instructions after `steps` steps without reaching a target, so it
+    /// never will.
+    Unreachable { start: String, steps: usize },
+    /// There are no ghosts, as no node ends in `A`.
+    NoGhosts,
+    /// Every ghost reaches targets, but never all at the same time.
+    NeverTogether,
+}
+
+impl fmt::Display for WalkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingNode(node) => write!(f, "the network has no node {node}"),
+            Self::Unreachable { start, steps } => write!(
+                f,
+                "the walk from {start} starts repeating after {steps} steps without reaching a target"
+            ),
+            Self::NoGhosts => write!(f, "no node ends in A for a ghost to start on"),
+            Self::NeverTogether => write!(f, "the ghosts are never all on targets at once"),
+        }
+    }
+}
+
+/// A part's answer: the number of steps, or why it can't be done.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepCount(pub Result<usize, WalkError>);
+
+impl fmt::Display for StepCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Ok(steps) => write!(f, "{steps}"),
+            Err(e) => write!(f, "unreachable, {e}"),
+        }
+    }
+}
+
+/// # Errors
+/// If `ZZZ` can't be reached from `AAA`.
+pub fn step_count(instructions: &[bool], network: &Network) -> Result<usize, WalkError> {
+    first_target("AAA", instructions, network, |node| node == "ZZZ")
+}
+
+/// The first step at which every ghost is on a node ending in `Z`.
+///
+/// Each ghost's walk is followed until it loops, noting every step it is on
+/// a target. If each ghost is on exactly one target per loop, at the end of
+/// the loop, the answer is the LCM of the loop lengths. Otherwise the steps
+/// at which every ghost is on a target are found with the Chinese Remainder
+/// Theorem.
+///
+/// # Errors
+/// If a ghost can't reach a target, or the ghosts never reach them together.
+pub fn simultaneous_step_count(
+    instructions: &[bool],
+    network: &Network,
+) -> Result<usize, WalkError> {
+    if let Some(missing) = network
+        .values()
+        .flat_map(|(l, r)| [l, r])
+        .find(|node| !network.contains_key(*node))
+    {
+        return Err(WalkError::MissingNode(missing.clone()));
+    }
+
+    let ghosts: Vec<GhostCycle> = network
+        .keys()
+        .filter(|k| k.ends_with('A'))
+        .map(|start| GhostCycle::analyse(start, instructions, network))
+        .collect::<Result<_, _>>()?;
+    if ghosts.is_empty() {
+        return Err(WalkError::NoGhosts);
+    }
+
+    // Only an early target can come before every ghost is looping.
+    let early = ghosts
+        .iter()
+        .flat_map(|g| &g.early)
+        .filter(|&&step| ghosts.iter().all(|g| g.on_target(step)))
+        .min();
+    if let Some(&step) = early {
+        return Ok(step);
+    }
+
+    if ghosts.iter().all(GhostCycle::targets_loop_ends) {
+        let steps = ghosts.iter().map(|g| g.cycle.length).fold(1, lcm);
+        if ghosts.iter().all(|g| g.on_target(steps)) {
+            return Ok(steps);
+        }
+    }
+
+    let looping_from = ghosts
+        .iter()
+        .map(|g| g.cycle.prefix)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let mut congruences = vec![(0, 1)];
+    for ghost in &ghosts {
+        let length = ghost.cycle.length as i128;
+        congruences = congruences
+            .iter()
+            .flat_map(|&c| {
+                ghost
+                    .looped
+                    .iter()
+                    .filter_map(move |&step| crt(c, (step as i128 % length, length)))
+            })
+            .collect();
+    }
+
+    congruences
+        .into_iter()
+        .map(|(residue, modulus)| {
+            let behind = (looping_from as i128 - residue).max(0);
+            residue + (behind + modulus - 1) / modulus * modulus
+        })
+        .min()
+        .map(|step| step as usize)
+        .ok_or(WalkError::NeverTogether)
+}
+
+/// When a ghost is on a target, worked out from the loop its walk falls into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GhostCycle {
+    /// Steps at which the ghost is on a target before its walk starts looping.
+    pub early: Vec<usize>,
+    /// Steps at which the ghost is on a target during its first loop, each of
+    /// which repeats every `cycle.length` steps.
+    pub looped: Vec<usize>,
+    pub cycle: Cycle,
+}
+
+impl GhostCycle {
+    /// Follows a ghost from `start` until its node and place in the
+    /// instructions repeat. Every node in `network` must lead somewhere.
+    fn analyse(start: &str, instructions: &[bool], network: &Network) -> Result<Self, WalkError> {
+        let history = cycle::hashed((start, 0), |&(node, i)| {
+            let (l, r) = &network[node];
+            let next = if instructions[i] { l } else { r };
+            (next.as_str(), (i + 1) % instructions.len())
+        });
+
+        let (early, looped): (Vec<usize>, Vec<usize>) = history
+            .states()
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter(|(_, (node, _))| node.ends_with('Z'))
+            .map(|(step, _)| step)
+            .partition(|&step| step < history.cycle.prefix);
+
+        if early.is_empty() && looped.is_empty() {
+            return Err(WalkError::Unreachable {
+                start: start.to_owned(),
+                steps: history.states().len(),
+            });
+        }
+        Ok(Self {
+            early,
+            looped,
+            cycle: history.cycle,
+        })
+    }
+
+    fn on_target(&self, step: usize) -> bool {
+        if step < self.cycle.prefix {
+            self.early.contains(&step)
+        } else {
+            self.looped.contains(&self.cycle.reduce(step))
+        }
+    }
+
+    /// Whether the ghost is only ever on a target at the end of each loop,
+    /// which is what makes the LCM of the loop lengths the answer.
+    fn targets_loop_ends(&self) -> bool {
+        self.early.is_empty() && matches!(self.looped[..], [step] if step % self.cycle.length == 0)
+    }
+}
+
+/// Combines `x ≡ a (mod m)` and `x ≡ b (mod n)` into a single congruence,
+/// for moduli that needn't be coprime, or `None` if they contradict.
+fn crt((a, m): (i128, i128), (b, n): (i128, i128)) -> Option<(i128, i128)> {
+    let ExtendedGcd { gcd, x, .. } = m.extended_gcd(&n);
+    if (b - a) % gcd != 0 {
+        return None;
+    }
+    let modulus = m / gcd * n;
+    let residue = (a + m * ((b - a) / gcd * x % (n / gcd))).rem_euclid(modulus);
+    Some((residue, modulus))
+}
+
+/// Steps from `start` to the first node that `is_target` accepts.
+///
+/// The walk is tracked by node and position in the instructions, so it gives
+/// up as soon as one of those repeats, as from there it can only go round in
+/// circles.
+fn first_target(
+    start: &str,
+    instructions: &[bool],
+    network: &Network,
+    is_target: impl Fn(&str) -> bool,
+) -> Result<usize, WalkError> {
+    let mut seen = HashSet::new();
+    let mut node = start;
+    for (steps, (i, left)) in (1..).zip(instructions.iter().enumerate().cycle()) {
+        if !seen.insert((node, i)) {
+            return Err(WalkError::Unreachable {
+                start: start.to_owned(),
+                steps: steps - 1,
+            });
+        }
+        let (l, r) = network
+            .get(node)
+            .ok_or_else(|| WalkError::MissingNode(node.to_owned()))?;
+        node = if *left { l } else { r };
+        if is_target(node) {
+            return Ok(steps);
+        }
+    }
+    unreachable!("the instructions cycle forever")
+}
+
+fn parse_puzzle(input: &str) -> PuzzleResult<(Vec<bool>, Network)> {
+    let puzzle: Vec<&str> = input.lines().collect();
+
+    let Some(instructions) = puzzle.first() else {
+        return Err(PuzzleError::malformed("expected a line of instructions"));
+    };
+    let instructions: Vec<bool> = instructions
+        .chars()
+        .enumerate()
+        .map(|(j, c)| match c {
+            'L' => Ok(true),
+            'R' => Ok(false),
+            _ => Err(PuzzleError::unexpected_char(c).at_column(j + 1).at_line(1)),
+        })
+        .collect::<PuzzleResult<_>>()?;
+    if instructions.is_empty() {
+        return Err(PuzzleError::malformed("expected at least one instruction").at_line(1));
+    }
+
+    let mut network = HashMap::new();
+
+    for (i, line) in puzzle.iter().enumerate().skip(2) {
+        let (key, values) = parse_node(line).map_err(|e| e.at_line(i + 1))?;
+        network.insert(key, values);
+    }
+
+    Ok((instructions, network))
+}
+
+fn parse_node(line: &str) -> PuzzleResult<(String, (String, String))> {
+    let malformed = || PuzzleError::malformed("expected a node like `AAA = (BBB, CCC)`");
+
+    let (key, values) = line.split_once(" = ").ok_or_else(malformed)?;
+    let (left, right) = values
+        .strip_prefix('(')
+        .and_then(|v| v.strip_suffix(')'))
+        .and_then(|v| v.split_once(", "))
+        .ok_or_else(malformed)?;
+
+    Ok((key.to_string(), (left.to_string(), right.to_string())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn part_one_one() {
+        let puzzle = Day08::parse("test1_part1").unwrap();
+        let result = Day08.part_one(&puzzle);
+        assert_eq!(result, StepCount(Ok(2)));
+    }
+
+    #[test]
+    fn part_one_two() {
+        let puzzle = Day08::parse("test2_part1").unwrap();
+        let result = Day08.part_one(&puzzle);
+        assert_eq!(result, StepCount(Ok(6)));
+    }
+
+    #[test]
+    fn part_two() {
+        let puzzle = Day08::parse("test_part2").unwrap();
+        let result = Day08.part_two(&puzzle);
+        assert_eq!(result, StepCount(Ok(6)));
+    }
+
+    const LOOPING: &str = "\
+LR
+
+AAA = (BBB, BBB)
+BBB = (AAA, AAA)
+CCA = (CCA, CCZ)
+CCZ = (CCA, CCA)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    #[test]
+    fn unreachable_targets() {
+        let (instructions, network) = Day08::parse_str(LOOPING).unwrap();
+        assert_eq!(
+            step_count(&instructions, &network),
+            Err(WalkError::Unreachable {
+                start: "AAA".to_owned(),
+                steps: 2
+            })
+        );
+        // The ghost starting at CCA gets to CCZ, but the one at AAA never does.
+        assert!(simultaneous_step_count(&instructions, &network).is_err());
+        assert_eq!(
+            Day08.part_one(&(instructions, network)).to_string(),
+            "unreachable, the walk from AAA starts repeating after 2 steps without reaching a target"
+        );
+    }
+
+    // PPA is on PPZ at steps 1, 4, 7, ... and QQA on QQZ at 2, 6, 10, ..., so
+    // they first meet at step 10 rather than at the LCM of 3 and 4.
+    const OFFSET: &str = "\
+L
+
+PPA = (PPZ, PPZ)
+PPZ = (PPB, PPB)
+PPB = (PPC, PPC)
+PPC = (PPZ, PPZ)
+QQA = (QQB, QQB)
+QQB = (QQZ, QQZ)
+QQZ = (QQC, QQC)
+QQC = (QQD, QQD)
+QQD = (QQB, QQB)
+";
+
+    #[test]
+    fn ghost_cycles() {
+        let (instructions, network) = Day08::parse_str(OFFSET).unwrap();
+        let ghost = GhostCycle::analyse("QQA", &instructions, &network).unwrap();
+        assert_eq!(
+            ghost.cycle,
+            Cycle {
+                prefix: 1,
+                length: 4
+            }
+        );
+        assert_eq!((ghost.early, ghost.looped), (vec![], vec![2]));
+        assert_eq!(simultaneous_step_count(&instructions, &network), Ok(10));
+    }
+
+    #[test]
+    fn ghosts_out_of_step() {
+        let out_of_step = OFFSET.replace("QQD = (QQB, QQB)", "QQD = (QQZ, QQZ)");
+        let (instructions, network) = Day08::parse_str(&out_of_step).unwrap();
+        // QQA is now on QQZ at steps 2, 5, 8, ..., always one behind PPA.
+        assert_eq!(
+            simultaneous_step_count(&instructions, &network),
+            Err(WalkError::NeverTogether)
+        );
+    }
+
+    #[test]
+    fn no_instructions() {
+        let error = Day08::parse_str("\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap_err();
+        assert_eq!(error.line(), Some(1));
+    }
+
+    #[test]
+    fn generalised_crt() {
+        assert_eq!(crt((1, 3), (2, 4)), Some((10, 12)));
+        assert_eq!(crt((2, 4), (0, 6)), Some((6, 12)));
+        assert_eq!(crt((1, 4), (0, 6)), None);
+    }
+}
@@ -0,0 +1,76 @@
+#![feature(iter_map_windows)]
+
+use aoc_utils::{parse_lines, PuzzleResult, Solution, Solver};
+use rayon::prelude::*;
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day09>(9)
+}
+
+#[derive(Debug, Default)]
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<isize>>;
+    type Output = isize;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        parse_lines(input, |l| {
+            l.split_ascii_whitespace().map(|n| Ok(n.parse()?)).collect()
+        })
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        oasis_sum(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        oasis_sum_reverse(input)
+    }
+}
+
+pub fn oasis_sum(histories: &[Vec<isize>]) -> isize {
+    histories.par_iter().map(|l| next_value(l)).sum()
+}
+
+pub fn oasis_sum_reverse(histories: &[Vec<isize>]) -> isize {
+    histories
+        .par_iter()
+        .map(|l| {
+            let mut l = l.clone();
+            l.reverse();
+            l
+        })
+        .map(|l| next_value(&l))
+        .sum()
+}
+
+fn next_value(line: &[isize]) -> isize {
+    let mut lasts = vec![*line.last().unwrap()];
+    let mut current_line = line.to_vec();
+    while current_line.iter().map_windows(|&[a, b]| a != b).any(|x| x) {
+        current_line = current_line.iter().map_windows(|&[a, b]| b - a).collect();
+        lasts.push(*current_line.last().unwrap());
+    }
+    lasts.into_iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let histories = Day09::parse("test_part1").unwrap();
+        let result = Day09.part_one(&histories);
+        assert_eq!(result, 114);
+    }
+
+    #[test]
+    fn part_two() {
+        let histories = Day09::parse("test_part1").unwrap();
+        let result = Day09.part_two(&histories);
+        assert_eq!(result, 2);
+    }
+}
@@ -1,70 +1,14 @@
-#![feature(iter_map_windows)]
-use std::path::Path;
-
-use aoc_utils::{get_entire_puzzle, Cli};
-use rayon::prelude::*;
+use aoc_utils::{Cli, Solution};
+use d09::Day09;
 
 fn main() {
-    let part_two = Cli::parse_args().part_two;
+    let cli = Cli::parse_args();
+    let histories = Day09::parse(cli.input_path()).unwrap_or_else(|e| e.exit());
 
-    let result = if part_two {
-        oasis_sum_reverse("input")
+    let result = if cli.part_two {
+        Day09.part_two(&histories)
     } else {
-        oasis_sum("input")
+        Day09.part_one(&histories)
     };
     println!("Puzzle result: {result}");
 }
-
-fn oasis_sum(input: impl AsRef<Path>) -> isize {
-    get_entire_puzzle(input)
-        .par_iter()
-        .map(|l| {
-            l.split_ascii_whitespace()
-                .map(|n| n.parse().unwrap())
-                .collect::<Vec<isize>>()
-        })
-        .map(|l| next_value(&l))
-        .sum()
-}
-
-fn oasis_sum_reverse(input: impl AsRef<Path>) -> isize {
-    get_entire_puzzle(input)
-        .par_iter()
-        .map(|l| {
-            l.split_ascii_whitespace()
-                .map(|n| n.parse().unwrap())
-                .collect::<Vec<isize>>()
-        })
-        .map(|mut l| {
-            l.reverse();
-            l
-        })
-        .map(|l| next_value(&l))
-        .sum()
-}
-
-fn next_value(line: &[isize]) -> isize {
-    let mut lasts = vec![*line.last().unwrap()];
-    let mut current_line = line.to_vec();
-    while current_line.iter().map_windows(|&[a, b]| a != b).any(|x| x) {
-        current_line = current_line.iter().map_windows(|&[a, b]| b - a).collect();
-        lasts.push(*current_line.last().unwrap());
-    }
-    lasts.into_iter().sum()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn part_one() {
-        let result = oasis_sum("test_part1");
-        assert_eq!(result, 114);
-    }
-
-    #[test]
-    fn part_two() {
-        let result = oasis_sum_reverse("test_part1");
-        assert_eq!(result, 2);
-    }
-}
@@ -0,0 +1,251 @@
+use aoc_utils::{FromChar, Grid, PuzzleError, PuzzleResult, Solution, Solver};
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day10>(10)
+}
+
+#[derive(Debug, Default)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    /// Where the animal starts, and the map of pipes.
+    type Input = (Coords, Map);
+    type Output = usize;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        create_map(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        let (start, map) = input;
+        loop_steps(*start, map)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        let (start, map) = input;
+        loop_area(*start, map)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Pipe {
+    NorthSouth,
+    EastWest,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    Ground,
+    Start,
+}
+
+impl FromChar for Pipe {
+    fn from_char(input: char) -> PuzzleResult<Self> {
+        let pipe = match input {
+            '|' => Self::NorthSouth,
+            '-' => Self::EastWest,
+            'L' => Self::NorthEast,
+            'J' => Self::NorthWest,
+            '7' => Self::SouthWest,
+            'F' => Self::SouthEast,
+            '.' => Self::Ground,
+            'S' => Self::Start,
+            _ => return Err(PuzzleError::unexpected_char(input)),
+        };
+        Ok(pipe)
+    }
+}
+
+type Coords = (isize, isize);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cell {
+    pipe: Pipe,
+    coords: Coords,
+}
+
+impl Cell {
+    const fn next_coords(&self) -> Option<(Coords, Coords)> {
+        let d = match self.pipe {
+            Pipe::NorthSouth => (
+                (self.coords.0 - 1, self.coords.1),
+                (self.coords.0 + 1, self.coords.1),
+            ),
+            Pipe::EastWest => (
+                (self.coords.0, self.coords.1 + 1),
+                (self.coords.0, self.coords.1 - 1),
+            ),
+            Pipe::NorthEast => (
+                (self.coords.0 - 1, self.coords.1),
+                (self.coords.0, self.coords.1 + 1),
+            ),
+            Pipe::NorthWest => (
+                (self.coords.0 - 1, self.coords.1),
+                (self.coords.0, self.coords.1 - 1),
+            ),
+            Pipe::SouthWest => (
+                (self.coords.0 + 1, self.coords.1),
+                (self.coords.0, self.coords.1 - 1),
+            ),
+            Pipe::SouthEast => (
+                (self.coords.0, self.coords.1 + 1),
+                (self.coords.0 + 1, self.coords.1),
+            ),
+            Pipe::Ground | Pipe::Start => return None,
+        };
+        Some(d)
+    }
+
+    fn next_pipe(&self, current_coord: &Coords) -> Coords {
+        let options = self
+            .next_coords()
+            .unwrap_or_else(|| panic!("Expected a direction {current_coord:?}"));
+        if options.0 == *current_coord {
+            options.1
+        } else {
+            options.0
+        }
+    }
+}
+
+pub type Map = Grid<Cell>;
+
+pub fn loop_steps(start: Coords, map: &Map) -> usize {
+    let loop_spec = loop_cells(map, &start);
+
+    loop_spec.len() / 2
+}
+
+pub fn loop_area(start: Coords, map: &Map) -> usize {
+    let mut map = map.clone();
+
+    let loop_spec = loop_cells(&map, &start);
+
+    map[start] = *loop_spec.first().unwrap();
+
+    let mut inside_loop = false;
+    let mut area = 0;
+
+    for row in map.rows() {
+        for cell in row {
+            let is_loop_cell = loop_spec.contains(cell);
+            if is_loop_cell
+                && (matches!(cell.pipe, Pipe::NorthEast)
+                    || matches!(cell.pipe, Pipe::NorthSouth)
+                    || matches!(cell.pipe, Pipe::NorthWest))
+            {
+                inside_loop = !inside_loop;
+            }
+            if inside_loop && !is_loop_cell {
+                area += 1;
+            }
+        }
+    }
+    area
+}
+
+fn create_map(puzzle: &str) -> PuzzleResult<(Coords, Map)> {
+    let pipes: Grid<Pipe> = puzzle.parse()?;
+    let cells = pipes
+        .iter()
+        .map(|(coords, &pipe)| Cell { pipe, coords })
+        .collect();
+
+    let Some(start) = pipes.find(|pipe| matches!(pipe, Pipe::Start)) else {
+        return Err(PuzzleError::malformed("missing the starting position 'S'"));
+    };
+
+    Ok((start, Grid::new(pipes.width(), pipes.height(), cells)))
+}
+
+fn starting_directions(map: &Map, starting_coords: &Coords) -> (Coords, Coords, Pipe) {
+    let lookup = vec![(-1, 0), (0, 1), (1, 0), (0, -1)];
+    let mut matches = vec![];
+    let mut matching_lookups = vec![];
+    for l in lookup {
+        let new_coords = (starting_coords.0 + l.0, starting_coords.1 + l.1);
+        let Some(cell) = map.get(new_coords) else {
+            continue;
+        };
+        let next_cells = cell.next_coords();
+        if next_cells.is_some()
+            && (next_cells.unwrap().0 == *starting_coords
+                || next_cells.unwrap().1 == *starting_coords)
+        {
+            matches.push(cell.coords);
+            matching_lookups.push(l);
+        }
+    }
+
+    let pipe = match (
+        matching_lookups.first().unwrap(),
+        matching_lookups.last().unwrap(),
+    ) {
+        ((-1, 0), (0, 1)) => Pipe::NorthEast,
+        ((-1, 0), (1, 0)) => Pipe::NorthSouth,
+        ((-1, 0), (0, -1)) => Pipe::NorthWest,
+        ((0, 1), (1, 0)) => Pipe::SouthEast,
+        ((0, 1), (0, -1)) => Pipe::EastWest,
+        ((1, 0), (0, -1)) => Pipe::SouthWest,
+        _ => panic!("Unexpected pipe config"),
+    };
+
+    (*matches.first().unwrap(), *matches.last().unwrap(), pipe)
+}
+
+fn loop_cells(map: &Map, start: &Coords) -> Vec<Cell> {
+    let (mut dir_a, mut dir_b, pipe) = starting_directions(map, start);
+    let start_cell = Cell {
+        pipe,
+        coords: *start,
+    };
+    let mut section_a = vec![start_cell];
+    let mut section_b = vec![start_cell];
+    while dir_a != dir_b {
+        let cell_a = map[dir_a];
+        dir_a = cell_a.next_pipe(&section_a.last().unwrap().coords);
+        section_a.push(cell_a);
+
+        let cell_b = map[dir_b];
+        dir_b = cell_b.next_pipe(&section_b.last().unwrap().coords);
+        section_b.push(cell_b);
+    }
+    section_a.push(map[dir_a]);
+    section_b.reverse();
+    section_b.pop();
+    section_a.extend(section_b);
+    section_a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let map = Day10::parse("test_part1").unwrap();
+        let result = Day10.part_one(&map);
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn part_two_one() {
+        let map = Day10::parse("test1_part2").unwrap();
+        let result = Day10.part_two(&map);
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn part_two_two() {
+        let map = Day10::parse("test2_part2").unwrap();
+        let result = Day10.part_two(&map);
+        assert_eq!(result, 10);
+    }
+
+    #[test]
+    fn missing_start() {
+        let error = Day10::parse_str("-L|F7\n7|-7|\n").unwrap_err();
+        assert!(error.to_string().contains("starting position"));
+    }
+}
@@ -0,0 +1,160 @@
+#![feature(iter_map_windows)]
+
+use aoc_utils::{parse_lines, PuzzleError, PuzzleResult, Solution, Solver};
+use clap::{builder::RangedU64ValueParser, Args};
+use itertools::Itertools;
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day11>(11)
+}
+
+#[derive(Debug, Args)]
+pub struct Day11 {
+    /// How many times larger each empty row or column becomes in part two.
+    #[arg(
+        long,
+        default_value_t = Day11::default().expansion,
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..),
+    )]
+    pub expansion: usize,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self {
+            expansion: 1_000_000,
+        }
+    }
+}
+
+impl Solution for Day11 {
+    type Input = Universe;
+    type Output = usize;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        parse_lines(input, parse_line)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        galaxy_distance_sum(input, 2)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        galaxy_distance_sum(input, self.expansion)
+    }
+}
+
+pub type Universe = Vec<Vec<char>>;
+
+fn parse_line(line: &str) -> PuzzleResult<Vec<char>> {
+    line.chars()
+        .enumerate()
+        .map(|(j, c)| match c {
+            '.' | '#' => Ok(c),
+            _ => Err(PuzzleError::unexpected_char(c).at_column(j + 1)),
+        })
+        .collect()
+}
+
+pub fn galaxy_distance_sum(universe: &Universe, expansion: usize) -> usize {
+    let (empty_rows, empty_columns) = empty_rows_and_columns(universe);
+
+    let mut galaxies = get_galaxies(universe);
+    expand_galaxies(&mut galaxies, &empty_rows, &empty_columns, expansion);
+    galaxies
+        .iter()
+        .combinations(2)
+        .map(|comb| distance(comb.first().unwrap(), comb.last().unwrap()))
+        .sum()
+}
+
+fn empty_rows_and_columns(universe: &Universe) -> (Vec<usize>, Vec<usize>) {
+    let mut empty_rows = vec![];
+    for (i, row) in universe.iter().enumerate() {
+        if row.iter().map_windows(|&[a, b]| a == b).all(|x| x) {
+            empty_rows.push(i);
+        }
+    }
+
+    let mut empty_columns = vec![];
+    let universe_width = universe.first().unwrap().len();
+
+    for j in 0..universe_width {
+        if universe
+            .iter()
+            .map(|r| r.get(j).unwrap())
+            .map_windows(|&[a, b]| a == b)
+            .all(|x| x)
+        {
+            empty_columns.push(j);
+        }
+    }
+
+    (empty_rows, empty_columns)
+}
+
+type Coord = (usize, usize);
+
+fn get_galaxies(universe: &Universe) -> Vec<Coord> {
+    let mut galaxies = vec![];
+    for (i, row) in universe.iter().enumerate() {
+        for (j, location) in row.iter().enumerate() {
+            if location == &'#' {
+                galaxies.push((i, j));
+            }
+        }
+    }
+    galaxies
+}
+
+fn expand_galaxies(
+    galaxies: &mut [Coord],
+    empty_rows: &[usize],
+    empty_columns: &[usize],
+    expansion: usize,
+) {
+    let expansion = expansion - 1;
+    for galaxy in galaxies.iter_mut() {
+        let new_rows = empty_rows.iter().filter(|x| **x < galaxy.0).count();
+        let new_columns = empty_columns.iter().filter(|x| **x < galaxy.1).count();
+
+        *galaxy = (
+            galaxy.0 + (new_rows * expansion),
+            galaxy.1 + (new_columns * expansion),
+        );
+    }
+}
+
+fn distance(galaxy_a: &Coord, galaxy_b: &Coord) -> usize {
+    let row_distance = galaxy_a.0.abs_diff(galaxy_b.0);
+    let column_distance = galaxy_a.1.abs_diff(galaxy_b.1);
+
+    row_distance + column_distance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let universe = Day11::parse("test_part1").unwrap();
+        let result = Day11::default().part_one(&universe);
+        assert_eq!(result, 374);
+    }
+
+    #[test]
+    fn part_two_one() {
+        let universe = Day11::parse("test_part1").unwrap();
+        let result = Day11 { expansion: 10 }.part_two(&universe);
+        assert_eq!(result, 1030);
+    }
+
+    #[test]
+    fn part_two_two() {
+        let universe = Day11::parse("test_part1").unwrap();
+        let result = Day11 { expansion: 100 }.part_two(&universe);
+        assert_eq!(result, 8410);
+    }
+}
@@ -0,0 +1,193 @@
+use std::str::FromStr;
+
+use aoc_utils::{parse_lines, FromChar, PuzzleError, PuzzleResult, Solution, Solver};
+use memoize::memoize;
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day12>(12)
+}
+
+#[derive(Debug, Default)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<SpringRecord>;
+    type Output = usize;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        parse_lines(input, SpringRecord::from_str)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        spring_puzzle_sum(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        spring_puzzle_sum_part2(input)
+    }
+}
+
+pub fn spring_puzzle_sum(records: &[SpringRecord]) -> usize {
+    records
+        .iter()
+        .map(|s| count_combinations(s.row.clone(), s.groups.clone()))
+        .sum()
+}
+
+pub fn spring_puzzle_sum_part2(records: &[SpringRecord]) -> usize {
+    records
+        .iter()
+        .map(SpringRecord::unfold)
+        .map(|s| count_combinations(s.row, s.groups))
+        .sum()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Spring {
+    Ok,
+    Bad,
+    Unknown,
+}
+
+impl FromChar for Spring {
+    fn from_char(input: char) -> PuzzleResult<Self> {
+        match input {
+            '#' => Ok(Self::Bad),
+            '.' => Ok(Self::Ok),
+            '?' => Ok(Self::Unknown),
+            _ => Err(PuzzleError::unexpected_char(input)),
+        }
+    }
+}
+
+type Springs = Vec<Spring>;
+type Groups = Vec<usize>;
+
+#[derive(Debug)]
+pub struct SpringRecord {
+    row: Springs,
+    groups: Groups,
+}
+
+impl FromStr for SpringRecord {
+    type Err = PuzzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((row, groups)) = s.split_once(' ') else {
+            return Err(PuzzleError::malformed("expected springs and group sizes"));
+        };
+        let row: Vec<Spring> = row
+            .char_indices()
+            .map(|(i, c)| Spring::from_char(c).map_err(|e| e.at_column(i + 1)))
+            .collect::<PuzzleResult<_>>()?;
+        let groups: Vec<usize> = groups
+            .split(',')
+            .map(|n| n.parse())
+            .collect::<Result<_, _>>()
+            .map_err(|e| PuzzleError::from(e).at_column(s.len() - groups.len() + 1))?;
+
+        Ok(Self { row, groups })
+    }
+}
+
+impl SpringRecord {
+    fn unfold(&self) -> Self {
+        let row = [self.row.as_slice(); 5].join(&Spring::Unknown);
+        let groups = self.groups.repeat(5);
+
+        Self { row, groups }
+    }
+}
+
+#[memoize]
+fn count_combinations(springs: Vec<Spring>, groups: Vec<usize>) -> usize {
+    let no_more_springs = springs.is_empty();
+    let no_more_groups = groups.is_empty();
+    if no_more_springs && no_more_groups {
+        return 1;
+    } else if no_more_springs && !no_more_groups {
+        return 0;
+    }
+
+    let spring_length = springs.len();
+    let front_spring = springs[0];
+    if matches!(front_spring, Spring::Ok) {
+        count_combinations(springs[1..spring_length].to_vec(), groups)
+    } else if matches!(front_spring, Spring::Unknown) {
+        let mut broken = springs.clone();
+        broken[0] = Spring::Bad;
+        let mut fixed = springs;
+        fixed[0] = Spring::Ok;
+        let total_broken = count_combinations(broken, groups.clone());
+        let total_fixed = count_combinations(fixed, groups);
+        total_broken + total_fixed
+    } else if no_more_groups
+        || spring_length < groups[0]
+        || springs[0..groups[0]]
+            .iter()
+            .filter(|s| matches!(s, Spring::Ok))
+            .count()
+            > 0
+        || (groups.len() > 1 && groups[0] + 1 >= spring_length)
+    {
+        0
+    } else {
+        let next_springs = springs[groups[0]..spring_length].to_vec();
+        let next_groups = groups[1..groups.len()].to_vec();
+
+        let total = if next_springs.is_empty() || next_groups.is_empty() {
+            count_combinations(next_springs, next_groups)
+        } else {
+            match next_springs.first() {
+                Some(Spring::Bad) => 0,
+                Some(Spring::Ok) => count_combinations(next_springs, next_groups),
+                Some(Spring::Unknown) => {
+                    let mut fixed = next_springs.clone();
+                    fixed[0] = Spring::Ok;
+                    count_combinations(fixed, next_groups)
+                }
+                None => panic!("Expected next_springs not to be empty"),
+            }
+        };
+
+        total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let records = Day12::parse("test_part1").unwrap();
+        let result = Day12.part_one(&records);
+        assert_eq!(result, 21);
+    }
+
+    #[test]
+    fn part_two() {
+        let records = Day12::parse("test_part1").unwrap();
+        let result = Day12.part_two(&records);
+        assert_eq!(result, 525152);
+    }
+
+    #[rstest]
+    #[case("## 2", 1)]
+    #[case("?? 1", 2)]
+    #[case("?? 1,1", 0)]
+    #[case("??. 1,1", 0)]
+    #[case(".?? 1,1", 0)]
+    #[case("?.? 1,1", 1)]
+    #[case("??? 1,1", 1)]
+    #[case("??# 1,1", 1)]
+    fn basic_count(#[case] input: &str, #[case] expected: usize) {
+        let spring_line = SpringRecord::from_str(input).unwrap();
+        let count = count_combinations(spring_line.row, spring_line.groups);
+
+        assert_eq!(count, expected);
+    }
+}
@@ -0,0 +1,179 @@
+use std::mem;
+
+use aoc_utils::{FromChar, Grid, PuzzleError, PuzzleResult, Solution, Solver};
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day13>(13)
+}
+
+#[derive(Debug, Default)]
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<TerrainPattern>;
+    type Output = usize;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        parse_puzzle(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        mirror_number(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        smudged_mirror_number(input)
+    }
+}
+
+pub fn mirror_number(patterns: &[TerrainPattern]) -> usize {
+    patterns.iter().map(find_reflections).sum()
+}
+
+pub fn smudged_mirror_number(patterns: &[TerrainPattern]) -> usize {
+    patterns.iter().map(find_smudged_reflections).sum()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Terrain {
+    Ash,
+    Rocks,
+}
+
+impl FromChar for Terrain {
+    fn from_char(c: char) -> PuzzleResult<Self> {
+        match c {
+            '#' => Ok(Self::Rocks),
+            '.' => Ok(Self::Ash),
+            _ => Err(PuzzleError::unexpected_char(c)),
+        }
+    }
+}
+
+impl Terrain {
+    const fn other(self) -> Self {
+        match self {
+            Self::Ash => Self::Rocks,
+            Self::Rocks => Self::Ash,
+        }
+    }
+}
+
+pub type TerrainPattern = Grid<Terrain>;
+
+fn parse_puzzle(input: &str) -> PuzzleResult<Vec<TerrainPattern>> {
+    let mut patterns = vec![];
+    let mut rows: Vec<Vec<Terrain>> = vec![];
+
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            if !rows.is_empty() {
+                patterns.push(Grid::from_rows(mem::take(&mut rows))?);
+            }
+            continue;
+        }
+        let row: Vec<Terrain> = line
+            .char_indices()
+            .map(|(j, c)| Terrain::from_char(c).map_err(|e| e.at_column(j + 1).at_line(i + 1)))
+            .collect::<PuzzleResult<_>>()?;
+        if rows.first().is_some_and(|first| first.len() != row.len()) {
+            return Err(PuzzleError::malformed("rows differ in length").at_line(i + 1));
+        }
+        rows.push(row);
+    }
+    if !rows.is_empty() {
+        patterns.push(Grid::from_rows(rows)?);
+    }
+
+    Ok(patterns)
+}
+
+fn find_reflections(pattern: &TerrainPattern) -> usize {
+    let row_reflection = find_reflection(pattern, 100, None);
+    let transpose_pattern = pattern.transpose();
+    let column_reflection = find_reflection(&transpose_pattern, 1, None);
+
+    match (row_reflection, column_reflection) {
+        (Some(r), None) => r,
+        (None, Some(c)) => c,
+        _ => panic!("Expected only 1 reflection"),
+    }
+}
+
+fn find_smudged_reflections(pattern: &TerrainPattern) -> usize {
+    let row_reflection = find_reflection(pattern, 100, None);
+    let mut transpose_pattern = pattern.transpose();
+    let column_reflection = find_reflection(&transpose_pattern, 1, None);
+
+    let mut smudge_pattern = pattern.clone();
+    let new_row_reflection = find_smudged_reflection(&mut smudge_pattern, 100, row_reflection);
+
+    let new_column_reflection =
+        find_smudged_reflection(&mut transpose_pattern, 1, column_reflection);
+
+    match (new_row_reflection, new_column_reflection) {
+        (Some(r), None) => r,
+        (None, Some(c)) => c,
+        _ => panic!("Expected only 1 reflection"),
+    }
+}
+
+fn find_smudged_reflection(
+    pattern: &mut TerrainPattern,
+    multiple: usize,
+    current_reflection: Option<usize>,
+) -> Option<usize> {
+    for i in 0..pattern.height() as isize {
+        for j in 0..pattern.width() as isize {
+            let current = pattern[(i, j)];
+            pattern[(i, j)] = current.other();
+            let new_reflection = find_reflection(pattern, multiple, current_reflection);
+            pattern[(i, j)] = current;
+            if new_reflection.is_some() && new_reflection != current_reflection {
+                return new_reflection;
+            }
+        }
+    }
+    None
+}
+
+fn find_reflection(
+    pattern: &TerrainPattern,
+    multiple: usize,
+    current_reflection: Option<usize>,
+) -> Option<usize> {
+    for i in 1..pattern.height() {
+        let symmetrical = (0..i)
+            .rev()
+            .zip(i..pattern.height())
+            .all(|(u, l)| pattern.row(u) == pattern.row(l));
+        if symmetrical {
+            let new_reflection = Some(i * multiple);
+            if new_reflection != current_reflection {
+                return new_reflection;
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let patterns = Day13::parse("test_part1").unwrap();
+        let result = Day13.part_one(&patterns);
+        assert_eq!(result, 405);
+    }
+
+    #[test]
+    fn part_two() {
+        let patterns = Day13::parse("test_part1").unwrap();
+        let result = Day13.part_two(&patterns);
+        assert_eq!(result, 400);
+    }
+}
@@ -1,168 +1,14 @@
-use std::{fs::read_to_string, path::Path};
-
-use aoc_utils::Cli;
+use aoc_utils::{Cli, Solution};
+use d13::Day13;
 
 fn main() {
-    let part_two = Cli::parse_args().part_two;
+    let cli = Cli::parse_args();
+    let patterns = Day13::parse(cli.input_path()).unwrap_or_else(|e| e.exit());
 
-    let result = if part_two {
-        smudged_mirror_number("input")
+    let result = if cli.part_two {
+        Day13.part_two(&patterns)
     } else {
-        mirror_number("input")
+        Day13.part_one(&patterns)
     };
     println!("Puzzle result: {result}");
 }
-
-fn mirror_number(input: impl AsRef<Path>) -> usize {
-    let input = read_to_string(input).unwrap();
-
-    parse_puzzle(&input).iter().map(find_reflections).sum()
-}
-
-fn smudged_mirror_number(input: impl AsRef<Path>) -> usize {
-    let input = read_to_string(input).unwrap();
-
-    parse_puzzle(&input)
-        .iter()
-        .map(find_smudged_reflections)
-        .sum()
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Terrain {
-    Ash,
-    Rocks,
-}
-
-impl Terrain {
-    fn from_char(c: char) -> Self {
-        match c {
-            '#' => Self::Rocks,
-            '.' => Self::Ash,
-            _ => panic!("Unrecognised pattern."),
-        }
-    }
-
-    const fn other(self) -> Self {
-        match self {
-            Self::Ash => Self::Rocks,
-            Self::Rocks => Self::Ash,
-        }
-    }
-}
-
-type TerrainPattern = Vec<Vec<Terrain>>;
-
-fn parse_puzzle(input: &str) -> Vec<TerrainPattern> {
-    let input = input.strip_suffix('\n').unwrap();
-
-    input
-        .split("\n\n")
-        .map(|p| {
-            p.split('\n')
-                .map(|l| l.chars().map(Terrain::from_char).collect())
-                .collect()
-        })
-        .collect()
-}
-
-fn find_reflections(pattern: &TerrainPattern) -> usize {
-    let row_reflection = find_reflection(pattern, 100, None);
-    let transpose_pattern = transpose(pattern);
-    let column_reflection = find_reflection(&transpose_pattern, 1, None);
-
-    match (row_reflection, column_reflection) {
-        (Some(r), None) => r,
-        (None, Some(c)) => c,
-        _ => panic!("Expected only 1 reflection"),
-    }
-}
-
-fn find_smudged_reflections(pattern: &TerrainPattern) -> usize {
-    let row_reflection = find_reflection(pattern, 100, None);
-    let mut transpose_pattern = transpose(pattern);
-    let column_reflection = find_reflection(&transpose_pattern, 1, None);
-
-    let mut smudge_pattern = pattern.clone();
-    let new_row_reflection = find_smudged_reflection(&mut smudge_pattern, 100, row_reflection);
-
-    let new_column_reflection =
-        find_smudged_reflection(&mut transpose_pattern, 1, column_reflection);
-
-    match (new_row_reflection, new_column_reflection) {
-        (Some(r), None) => r,
-        (None, Some(c)) => c,
-        _ => panic!("Expected only 1 reflection"),
-    }
-}
-
-fn find_smudged_reflection(
-    pattern: &mut TerrainPattern,
-    multiple: usize,
-    current_reflection: Option<usize>,
-) -> Option<usize> {
-    for i in 0..pattern.len() {
-        for j in 0..pattern[0].len() {
-            let current = pattern[i][j];
-            pattern[i][j] = current.other();
-            let new_reflection = find_reflection(pattern, multiple, current_reflection);
-            pattern[i][j] = current;
-            if new_reflection.is_some() && new_reflection != current_reflection {
-                return new_reflection;
-            }
-        }
-    }
-    None
-}
-
-fn find_reflection(
-    pattern: &TerrainPattern,
-    multiple: usize,
-    current_reflection: Option<usize>,
-) -> Option<usize> {
-    for i in 1..pattern.len() {
-        let (upper, lower) = pattern.split_at(i);
-        let symmetrical = upper
-            .iter()
-            .rev()
-            .zip(lower)
-            .map(|(u, l)| u == l)
-            .all(|x| x);
-        if symmetrical {
-            let new_reflection = Some(i * multiple);
-            if new_reflection != current_reflection {
-                return new_reflection;
-            }
-        }
-    }
-
-    None
-}
-
-fn transpose(pattern: &TerrainPattern) -> TerrainPattern {
-    let mut transpose_pattern = vec![vec![]; pattern[0].len()];
-    for row in pattern {
-        for (j, value) in row.iter().enumerate() {
-            transpose_pattern[j].push(*value);
-        }
-    }
-
-    transpose_pattern
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part_one() {
-        let result = mirror_number("test_part1");
-        assert_eq!(result, 405);
-    }
-
-    #[test]
-    fn part_two() {
-        let result = smudged_mirror_number("test_part1");
-        assert_eq!(result, 400);
-    }
-}
@@ -0,0 +1,171 @@
+use aoc_utils::{cycle, FromChar, Grid, PuzzleError, PuzzleResult, Solution, Solver};
+use clap::Args;
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day14>(14)
+}
+
+#[derive(Debug, Args)]
+pub struct Day14 {
+    /// How many spin cycles to run in part two.
+    #[arg(long, default_value_t = Day14::default().cycles)]
+    pub cycles: usize,
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Self {
+            cycles: 1_000_000_000,
+        }
+    }
+}
+
+impl Solution for Day14 {
+    type Input = Platform;
+    type Output = usize;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        total_beam_load(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        total_beam_load_spin_cycle(input, self.cycles)
+    }
+}
+
+pub fn total_beam_load(platform: &Platform) -> usize {
+    let rotated_platform = platform.rotate_counter_clockwise();
+    let titled_platform = tilt_platform(&rotated_platform);
+    load_sum(&titled_platform)
+}
+
+/// The load after `cycles` spin cycles.
+///
+/// The platform soon settles into a loop, so spin until a platform repeats
+/// and then jump ahead to where `cycles` lands within the loop.
+pub fn total_beam_load_spin_cycle(platform: &Platform, cycles: usize) -> usize {
+    let history = cycle::hashed(platform.rotate_counter_clockwise(), spin_cycle);
+    load_sum(history.state_at(cycles))
+}
+
+/// Tilts the platform north, west, south and then east.
+///
+/// Expects, and returns, the platform rotated so that north is on the left.
+fn spin_cycle(platform: &Platform) -> Platform {
+    let mut platform = platform.clone();
+    for _ in 0..4 {
+        platform = tilt_platform(&platform);
+        platform = platform.rotate_clockwise();
+    }
+    platform
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Terrain {
+    Round,
+    Cube,
+    Ground,
+}
+
+impl FromChar for Terrain {
+    fn from_char(c: char) -> PuzzleResult<Self> {
+        match c {
+            'O' => Ok(Self::Round),
+            '#' => Ok(Self::Cube),
+            '.' => Ok(Self::Ground),
+            _ => Err(PuzzleError::unexpected_char(c)),
+        }
+    }
+}
+
+type Row = Vec<Terrain>;
+pub type Platform = Grid<Terrain>;
+
+fn tilt_platform(platform: &Platform) -> Platform {
+    let cells = platform.rows().flat_map(tilt_row).collect();
+    Grid::new(platform.width(), platform.height(), cells)
+}
+
+fn tilt_row(row: &[Terrain]) -> Row {
+    let mut cube_positions = vec![];
+    let mut round_counts = vec![];
+    let mut round_count: usize = 0;
+    for (i, terrain) in row.iter().enumerate() {
+        match *terrain {
+            Terrain::Ground => (),
+            Terrain::Cube => {
+                cube_positions.push(i);
+                round_counts.push(round_count);
+                round_count = 0
+            }
+            Terrain::Round => round_count += 1,
+        }
+    }
+    round_counts.push(round_count);
+
+    round_counts.reverse();
+
+    let mut titled_row = vec![];
+    let mut current_round_count = round_counts.pop().unwrap_or(0);
+    for i in 0..row.len() {
+        if cube_positions.contains(&i) {
+            titled_row.push(Terrain::Cube);
+            current_round_count = round_counts.pop().unwrap_or(0);
+        } else if current_round_count == 0 {
+            titled_row.push(Terrain::Ground);
+        } else {
+            current_round_count -= 1;
+            titled_row.push(Terrain::Round);
+        }
+    }
+    titled_row
+}
+
+fn load_sum(platform: &Platform) -> usize {
+    let mut load_sum = 0;
+    let length = platform.width();
+    for row in platform.rows() {
+        for (i, point) in row.iter().enumerate() {
+            let multiplier = length - i;
+            if matches!(point, Terrain::Round) {
+                load_sum += multiplier;
+            }
+        }
+    }
+    load_sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let platform = Day14::parse("test_part1").unwrap();
+        let result = Day14::default().part_one(&platform);
+        assert_eq!(result, 136);
+    }
+
+    #[test]
+    fn part_two() {
+        let platform = Day14::parse("test_part1").unwrap();
+        let result = Day14::default().part_two(&platform);
+        assert_eq!(result, 64);
+    }
+
+    #[test]
+    fn spin_cycle_matches_brute_force() {
+        let platform = Day14::parse("test_part1").unwrap();
+        let mut spun = platform.rotate_counter_clockwise();
+        for cycles in 0..40 {
+            let result = total_beam_load_spin_cycle(&platform, cycles);
+            assert_eq!(result, load_sum(&spun), "after {cycles} cycles");
+            spun = spin_cycle(&spun);
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use aoc_utils::{PuzzleError, PuzzleResult, Solution, Solver};
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day15>(15)
+}
+
+#[derive(Debug, Default)]
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Instruction>;
+    type Output = usize;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        parse_puzzle(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        sequence_hash(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        focusing_power(input)
+    }
+}
+
+pub fn sequence_hash(instructions: &[Instruction]) -> usize {
+    instructions.iter().map(|i| simple_hash(&i.text)).sum()
+}
+
+fn simple_hash(value: &str) -> usize {
+    let mut hash_value = 0;
+
+    for v in value.bytes() {
+        hash_value += v as usize;
+        hash_value *= 17;
+        hash_value %= 256;
+    }
+    hash_value
+}
+
+pub fn focusing_power(instructions: &[Instruction]) -> usize {
+    let mut holiday_map = HolidayMap::new();
+
+    for instruction in instructions {
+        holiday_map.evaluate(&instruction.step);
+    }
+
+    holiday_map.power()
+}
+
+/// What a step of the initialization sequence does to the boxes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Step {
+    Remove(String),
+    Insert(String, usize),
+}
+
+/// A step along with its text, which is what part one hashes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub text: String,
+    pub step: Step,
+}
+
+impl Instruction {
+    /// Parses a step that starts at `column` of the line.
+    fn parse(text: &str, column: usize) -> PuzzleResult<Self> {
+        let step = if let Some(label) = text.strip_suffix('-') {
+            Step::Remove(label.to_owned())
+        } else if let Some((label, focal)) = text.split_once('=') {
+            let focal = focal
+                .parse()
+                .map_err(|e| PuzzleError::from(e).at_column(column + label.len() + 1))?;
+            Step::Insert(label.to_owned(), focal)
+        } else {
+            return Err(PuzzleError::malformed(format!(
+                "expected a step ending in '-' or '=' and a focal length, got {text:?}"
+            ))
+            .at_column(column));
+        };
+        Ok(Self {
+            text: text.to_owned(),
+            step,
+        })
+    }
+}
+
+fn parse_puzzle(input: &str) -> PuzzleResult<Vec<Instruction>> {
+    let mut column = 1;
+    input
+        .trim()
+        .split(',')
+        .map(|text| {
+            let instruction = Instruction::parse(text, column).map_err(|e| e.at_line(1));
+            column += text.len() + 1;
+            instruction
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+struct Lens {
+    label: String,
+    focal: usize,
+}
+
+#[derive(Debug)]
+struct HolidayMap {
+    // Don't want to clash with the std HashMap...
+    boxes: Vec<Vec<Lens>>,
+}
+
+impl HolidayMap {
+    fn new() -> Self {
+        Self {
+            boxes: vec![Vec::new(); 256],
+        }
+    }
+
+    fn evaluate(&mut self, step: &Step) {
+        match step {
+            Step::Remove(label) => self.remove(label),
+            Step::Insert(label, focal) => self.add(label.clone(), *focal),
+        }
+    }
+
+    fn add(&mut self, label: String, focal: usize) {
+        let hash = simple_hash(&label);
+        let box_ = &mut self.boxes[hash];
+        for lens in box_.iter_mut() {
+            if lens.label == label {
+                lens.focal = focal;
+                return;
+            }
+        }
+
+        box_.push(Lens { label, focal });
+    }
+
+    fn remove(&mut self, label: &str) {
+        let hash = simple_hash(label);
+        let box_ = &mut self.boxes[hash];
+        for (i, lens) in box_.iter().enumerate() {
+            if lens.label == label {
+                box_.remove(i);
+                return;
+            }
+        }
+    }
+
+    fn power(&self) -> usize {
+        let mut power = 0;
+        for (i, box_) in self.boxes.iter().enumerate() {
+            for (j, len) in box_.iter().enumerate() {
+                power += (i + 1) * (j + 1) * len.focal;
+            }
+        }
+        power
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let instructions = Day15::parse("test_part1").unwrap();
+        let result = Day15.part_one(&instructions);
+        assert_eq!(result, 1320);
+    }
+
+    #[test]
+    fn hash_single_character() {
+        let result = simple_hash("H");
+        assert_eq!(result, 200);
+    }
+
+    #[test]
+    fn hash_multiple_character() {
+        let result = simple_hash("rn=1");
+        assert_eq!(result, 30);
+    }
+
+    #[test]
+    fn part_two() {
+        let instructions = Day15::parse("test_part1").unwrap();
+        let result = Day15.part_two(&instructions);
+        assert_eq!(result, 145);
+    }
+
+    #[test]
+    fn parse_bad_steps() {
+        let error = parse_puzzle("rn=1,cm=x").unwrap_err();
+        assert_eq!((error.line(), error.column()), (Some(1), Some(9)));
+        let error = parse_puzzle("rn=1,cm").unwrap_err();
+        assert_eq!(error.column(), Some(6));
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::HashSet;
+
+use aoc_utils::{
+    search::flood_fill, Coord, Direction, FromChar, Grid, PuzzleError, PuzzleResult, Solution,
+    Solver,
+};
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day16>(16)
+}
+
+#[derive(Debug, Default)]
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Contraption;
+    type Output = usize;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        energized_tiles(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        energized_tiles_maximum(input)
+    }
+}
+
+pub fn energized_tiles(contraption: &Contraption) -> usize {
+    let start_beam = Beam {
+        location: (0, 0),
+        direction: BeamDirection(Direction::Right),
+    };
+    fire_beam(contraption, start_beam)
+}
+
+pub fn energized_tiles_maximum(contraption: &Contraption) -> usize {
+    create_beams(contraption.width(), contraption.height())
+        .iter()
+        .map(|b| fire_beam(contraption, *b))
+        .max()
+        .unwrap()
+}
+
+fn fire_beam(contraption: &Contraption, start_beam: Beam) -> usize {
+    let energized = flood_fill([start_beam], |beam| {
+        let tile = contraption[beam.location];
+        let mut next_beams = tile.next_beams(beam);
+        next_beams.retain(|b| contraption.contains(b.location));
+        next_beams
+    });
+
+    energized
+        .iter()
+        .map(|b| b.location)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+fn create_beams(width: usize, height: usize) -> Vec<Beam> {
+    let mut top = (0..width)
+        .map(|j| Beam {
+            location: (0, j as isize),
+            direction: BeamDirection(Direction::Down),
+        })
+        .collect::<Vec<_>>();
+
+    let left = (0..height)
+        .map(|i| Beam {
+            location: (i as isize, 0),
+            direction: BeamDirection(Direction::Right),
+        })
+        .collect::<Vec<_>>();
+
+    let right = (0..height)
+        .map(|i| Beam {
+            location: (i as isize, width as isize - 1),
+            direction: BeamDirection(Direction::Left),
+        })
+        .collect::<Vec<_>>();
+
+    let bottom = (0..width)
+        .map(|j| Beam {
+            location: (height as isize - 1, j as isize),
+            direction: BeamDirection(Direction::Up),
+        })
+        .collect::<Vec<_>>();
+
+    top.extend(left);
+    top.extend(right);
+    top.extend(bottom);
+
+    top
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Tile {
+    Empty,
+    RightMirror,
+    LeftMirror,
+    HorizontalSplitter,
+    VerticalSplitter,
+}
+
+impl FromChar for Tile {
+    fn from_char(c: char) -> PuzzleResult<Self> {
+        match c {
+            '.' => Ok(Self::Empty),
+            '/' => Ok(Self::RightMirror),
+            '\\' => Ok(Self::LeftMirror),
+            '-' => Ok(Self::HorizontalSplitter),
+            '|' => Ok(Self::VerticalSplitter),
+            _ => Err(PuzzleError::unexpected_char(c)),
+        }
+    }
+}
+
+impl Tile {
+    fn next_beams(self, beam: &Beam) -> Vec<Beam> {
+        let location = &beam.location;
+        let beam_dir = beam.direction;
+        match self {
+            Self::Empty => vec![beam_dir.next_beam(location)],
+            Self::RightMirror => vec![beam_dir.reflect_right().next_beam(location)],
+            Self::LeftMirror => vec![beam_dir.reflect_left().next_beam(location)],
+            Self::HorizontalSplitter => beam_dir
+                .split_horizontal()
+                .iter()
+                .map(|d| d.next_beam(location))
+                .collect(),
+            Self::VerticalSplitter => beam_dir
+                .split_vertical()
+                .iter()
+                .map(|d| d.next_beam(location))
+                .collect(),
+        }
+    }
+}
+
+pub type Contraption = Grid<Tile>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Beam {
+    location: Coord,
+    direction: BeamDirection,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct BeamDirection(Direction);
+
+impl BeamDirection {
+    const fn next_beam(self, location: &Coord) -> Beam {
+        let new_location = self.0.next_coord(location);
+
+        Beam {
+            location: new_location,
+            direction: self,
+        }
+    }
+
+    const fn reflect_right(self) -> Self {
+        Self(match self.0 {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Down,
+        })
+    }
+
+    const fn reflect_left(self) -> Self {
+        Self(match self.0 {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Up,
+        })
+    }
+
+    fn split_horizontal(self) -> Vec<Self> {
+        match self.0 {
+            Direction::Up | Direction::Down => vec![Self(Direction::Left), Self(Direction::Right)],
+            Direction::Right | Direction::Left => vec![self],
+        }
+    }
+
+    fn split_vertical(self) -> Vec<Self> {
+        match self.0 {
+            Direction::Left | Direction::Right => vec![Self(Direction::Up), Self(Direction::Down)],
+            Direction::Up | Direction::Down => vec![self],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let contraption = Day16::parse("test_part1").unwrap();
+        let result = Day16.part_one(&contraption);
+        assert_eq!(result, 46);
+    }
+
+    #[test]
+    fn part_two() {
+        let contraption = Day16::parse("test_part1").unwrap();
+        let result = Day16.part_two(&contraption);
+        assert_eq!(result, 51);
+    }
+
+    #[test]
+    fn part_one_wide() {
+        let contraption = Day16::parse("test_wide").unwrap();
+        let result = Day16.part_one(&contraption);
+        assert_eq!(result, 7);
+    }
+
+    #[test]
+    fn part_two_wide() {
+        let contraption = Day16::parse("test_wide").unwrap();
+        let result = Day16.part_two(&contraption);
+        assert_eq!(result, 22);
+    }
+}
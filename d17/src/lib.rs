@@ -0,0 +1,330 @@
+use std::fmt;
+
+use aoc_utils::{
+    search::{astar_with, manhattan, DenseCosts},
+    Coord, Direction, Grid, PuzzleError, PuzzleResult, Solution, Solver,
+};
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day17>(17)
+}
+
+#[derive(Debug, Default)]
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = City;
+    type Output = HeatLoss;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        parse_puzzle(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        HeatLoss(minimum_heat_loss(input, Crucible::NORMAL))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        HeatLoss(minimum_heat_loss(input, Crucible::ULTRA))
+    }
+}
+
+/// The least heat lost reaching the factory, or `None` if the crucible can't.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeatLoss(pub Option<usize>);
+
+impl fmt::Display for HeatLoss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(heat_loss) => write!(f, "{heat_loss}"),
+            None => write!(f, "unreachable"),
+        }
+    }
+}
+
+/// The heat lost entering each block of the city.
+pub type City = Grid<usize>;
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Right,
+    Direction::Down,
+    Direction::Left,
+];
+
+/// How far a crucible has to, and is allowed to, move in a straight line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crucible {
+    /// Blocks to move before turning or stopping at the goal.
+    pub min_run: usize,
+    /// Blocks after which the crucible has to turn.
+    pub max_run: usize,
+}
+
+impl Crucible {
+    pub const NORMAL: Self = Self {
+        min_run: 1,
+        max_run: 3,
+    };
+    pub const ULTRA: Self = Self {
+        min_run: 4,
+        max_run: 10,
+    };
+
+    fn moves(self, state: &State) -> impl Iterator<Item = Direction> + '_ {
+        let turn = state.run >= self.min_run;
+        let straight = state.run < self.max_run;
+        let facing = state.direction;
+        DIRECTIONS.into_iter().filter(move |&d| {
+            if d == facing {
+                straight
+            } else {
+                turn && d != opposite(facing)
+            }
+        })
+    }
+}
+
+const fn opposite(direction: Direction) -> Direction {
+    match direction {
+        Direction::Up => Direction::Down,
+        Direction::Right => Direction::Left,
+        Direction::Down => Direction::Up,
+        Direction::Left => Direction::Right,
+    }
+}
+
+/// Where the crucible is, which way it is moving and how far it has gone that way.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct State {
+    position: Coord,
+    direction: Direction,
+    run: usize,
+}
+
+/// The least heat lost moving a crucible from the top left to the bottom right.
+#[must_use]
+pub fn minimum_heat_loss(city: &City, crucible: Crucible) -> Option<usize> {
+    crucible_path(city, crucible).map(|path| path.heat_loss)
+}
+
+/// The route losing the least heat from the top left to the bottom right, if
+/// the crucible can get there at all.
+#[must_use]
+pub fn crucible_path(city: &City, crucible: Crucible) -> Option<CruciblePath> {
+    let (width, runs) = (city.width(), crucible.max_run + 1);
+    let goal = (city.height() as isize - 1, width as isize - 1);
+    // The crucible starts at the factory, without needing to move at all.
+    if goal == (0, 0) {
+        return Some(CruciblePath {
+            heat_loss: 0,
+            moves: vec![],
+        });
+    }
+    // Each block left to cross costs at least this much, which keeps the
+    // heuristic from overestimating when some blocks cost nothing.
+    let cheapest = city
+        .iter()
+        .map(|(_, &heat_loss)| heat_loss)
+        .min()
+        .unwrap_or(0);
+
+    let costs = DenseCosts::new(city.height() * width * 4 * runs, |s: &State| {
+        let cell = s.position.0 as usize * width + s.position.1 as usize;
+        (cell * 4 + s.direction as usize) * runs + s.run
+    });
+    let starts = [Direction::Right, Direction::Down].map(|direction| State {
+        position: (0, 0),
+        direction,
+        run: 0,
+    });
+    let neighbours = |state: &State| {
+        crucible
+            .moves(state)
+            .filter_map(|direction| {
+                let position = direction.next_coord(&state.position);
+                let run = if direction == state.direction {
+                    state.run + 1
+                } else {
+                    1
+                };
+                let next = State {
+                    position,
+                    direction,
+                    run,
+                };
+                city.get(position).map(|&heat_loss| (next, heat_loss))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    astar_with(
+        costs,
+        starts,
+        neighbours,
+        |s| manhattan(s.position, goal) * cheapest,
+        |s| s.position == goal && s.run >= crucible.min_run,
+    )
+    .map(|path| CruciblePath {
+        heat_loss: path.cost,
+        moves: path
+            .states
+            .iter()
+            .skip(1)
+            .map(|s| (s.position, s.direction))
+            .collect(),
+    })
+}
+
+/// A crucible's route through the city.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CruciblePath {
+    pub heat_loss: usize,
+    /// Each block entered after leaving the start, and the direction it was entered in.
+    pub moves: Vec<(Coord, Direction)>,
+}
+
+impl CruciblePath {
+    /// The city with the route drawn over it in arrows, like the puzzle text.
+    #[must_use]
+    pub fn render(&self, city: &City) -> String {
+        let mut drawing =
+            city.map(|heat_loss| char::from_digit(*heat_loss as u32, 10).unwrap_or('?'));
+        for (position, direction) in &self.moves {
+            drawing[*position] = match direction {
+                Direction::Up => '^',
+                Direction::Right => '>',
+                Direction::Down => 'v',
+                Direction::Left => '<',
+            };
+        }
+        drawing.to_string()
+    }
+}
+
+fn parse_puzzle(input: &str) -> PuzzleResult<City> {
+    let rows: Vec<Vec<usize>> = input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| {
+            l.char_indices()
+                .map(|(j, c)| match c.to_digit(10) {
+                    Some(d) => Ok(d as usize),
+                    None => Err(PuzzleError::unexpected_char(c)
+                        .at_column(j + 1)
+                        .at_line(i + 1)),
+                })
+                .collect()
+        })
+        .collect::<PuzzleResult<_>>()?;
+    if rows.first().is_none_or(Vec::is_empty) {
+        return Err(PuzzleError::malformed("expected at least one row"));
+    }
+    Grid::from_rows(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn factory_at_the_start() {
+        let city = Day17::parse_str("5\n").unwrap();
+        assert_eq!(Day17.part_one(&city), HeatLoss(Some(0)));
+        assert_eq!(Day17.part_two(&city), HeatLoss(Some(0)));
+    }
+
+    #[test]
+    fn unreachable_factory() {
+        let city = Day17::parse_str("1111\n").unwrap();
+        assert_eq!(Day17.part_two(&city), HeatLoss(None));
+        assert_eq!(Day17.part_two(&city).to_string(), "unreachable");
+    }
+
+    #[test]
+    fn blocks_without_heat_loss() {
+        let city = Day17::parse_str("1500\n4020\n0501\n9010\n").unwrap();
+        assert_eq!(minimum_heat_loss(&city, Crucible::NORMAL), Some(6));
+    }
+
+    #[test]
+    fn part_one() {
+        let puzzle = Day17::parse("test_part1").unwrap();
+        let result = Day17.part_one(&puzzle);
+        assert_eq!(result, HeatLoss(Some(102)));
+    }
+
+    #[test]
+    fn short_input() {
+        let puzzle = Day17::parse("short_test").unwrap();
+        let result = Day17.part_one(&puzzle);
+        assert_eq!(result, HeatLoss(Some(7)));
+    }
+
+    #[test]
+    fn part_two() {
+        let puzzle = Day17::parse("test_part1").unwrap();
+        let result = Day17.part_two(&puzzle);
+        assert_eq!(result, HeatLoss(Some(94)));
+    }
+
+    #[test]
+    fn part_two_short_input() {
+        let puzzle = Day17::parse("test_part2").unwrap();
+        let result = Day17.part_two(&puzzle);
+        assert_eq!(result, HeatLoss(Some(71)));
+    }
+
+    #[test]
+    fn custom_crucible() {
+        let city = Day17::parse("test_part1").unwrap();
+        let goal = (city.height() as isize - 1, city.width() as isize - 1);
+
+        // Without a run limit the crucible just takes the cheapest path.
+        let unlimited = Crucible {
+            min_run: 1,
+            max_run: city.width().max(city.height()),
+        };
+        let cheapest = aoc_utils::search::dijkstra(
+            [(0, 0)],
+            |&c| city.neighbours(c).map(|n| (n, city[n])).collect::<Vec<_>>(),
+            |&c| c == goal,
+        );
+        assert_eq!(
+            minimum_heat_loss(&city, unlimited),
+            cheapest.map(|path| path.cost)
+        );
+
+        let too_long = Crucible {
+            min_run: 20,
+            max_run: 20,
+        };
+        assert_eq!(minimum_heat_loss(&city, too_long), None);
+    }
+
+    #[test]
+    fn render_path() {
+        let city = Day17::parse("test_part2").unwrap();
+        let path = crucible_path(&city, Crucible::ULTRA).unwrap();
+        assert_eq!(path.heat_loss, 71);
+        assert_eq!(
+            path.render(&city),
+            "\
+1>>>>>>>1111
+9999999v9991
+9999999v9991
+9999999v9991
+9999999v>>>>
+"
+        );
+
+        let city = Day17::parse("test_part1").unwrap();
+        let path = crucible_path(&city, Crucible::NORMAL).unwrap();
+        let lost: usize = path.moves.iter().map(|(c, _)| city[*c]).sum();
+        assert_eq!(lost, path.heat_loss);
+        assert!(path
+            .render(&city)
+            .starts_with("2>>34^>>>1323\n32v>>>35v5623\n"));
+    }
+}
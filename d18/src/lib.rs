@@ -0,0 +1,177 @@
+use std::str::FromStr;
+
+use aoc_utils::{parse_lines, Coord, Direction, PuzzleError, PuzzleResult, Solution, Solver};
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day18>(18)
+}
+
+#[derive(Debug, Default)]
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Record>;
+    type Output = isize;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        parse_puzzle(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        lava_volume(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        lava_volume_hex(input)
+    }
+}
+
+pub fn lava_volume(dig_plan: &[Record]) -> isize {
+    let mut coordinates: Vec<Coord> = vec![];
+    let mut current_position: Coord = (0, 0);
+    let mut boundary_length = 0;
+    for record in dig_plan {
+        current_position = record
+            .direction
+            .next_coord_far(&current_position, record.distance);
+        boundary_length += record.distance;
+        coordinates.push(current_position);
+    }
+    let area = shoelace_formula(&coordinates);
+
+    // Pick's theorem
+    let internal_points = area + 1 - (boundary_length / 2);
+
+    boundary_length + internal_points
+}
+
+pub fn lava_volume_hex(dig_plan: &[Record]) -> isize {
+    let mut coordinates: Vec<Coord> = vec![];
+    let mut current_position: Coord = (0, 0);
+    let mut boundary_length = 0;
+    for record in dig_plan {
+        let (direction, distance) = record.hex_instruction();
+        current_position = direction.next_coord_far(&current_position, distance);
+        boundary_length += distance;
+        coordinates.push(current_position);
+    }
+    let area = shoelace_formula(&coordinates);
+
+    // Pick's theorem
+    let internal_points = area + 1 - (boundary_length / 2);
+
+    boundary_length + internal_points
+}
+
+fn shoelace_formula(coordinates: &[Coord]) -> isize {
+    let result: isize = coordinates
+        .windows(2)
+        .map(|win| (win[0], win[1]))
+        .map(|(a, b)| (a.0 * b.1) - (a.1 * b.0))
+        .sum();
+
+    (result / 2).abs()
+}
+
+#[derive(Debug)]
+pub struct Record {
+    direction: Direction,
+    distance: isize,
+    hex_instruction: (Direction, isize),
+}
+
+impl FromStr for Record {
+    type Err = PuzzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(' ').collect();
+        let [direction, distance, colour] = parts[..] else {
+            return Err(PuzzleError::malformed(
+                "expected a direction, distance and colour",
+            ));
+        };
+
+        let direction = match direction {
+            "U" => Direction::Up,
+            "R" => Direction::Right,
+            "L" => Direction::Left,
+            "D" => Direction::Down,
+            _ => {
+                return Err(
+                    PuzzleError::malformed(format!("unrecognised direction {direction:?}"))
+                        .at_column(1),
+                )
+            }
+        };
+        let distance = distance
+            .parse()
+            .map_err(|e| PuzzleError::from(e).at_column(3))?;
+        let hex_instruction =
+            decode_colour(colour).map_err(|e| e.at_column(s.len() - colour.len() + 1))?;
+
+        Ok(Self {
+            direction,
+            distance,
+            hex_instruction,
+        })
+    }
+}
+
+impl Record {
+    const fn hex_instruction(&self) -> (Direction, isize) {
+        self.hex_instruction
+    }
+}
+
+/// Decodes the real instruction hidden in a colour like `(#70c710)`.
+fn decode_colour(colour: &str) -> PuzzleResult<(Direction, isize)> {
+    let Some(hex) = colour
+        .strip_prefix("(#")
+        .and_then(|c| c.strip_suffix(')'))
+        .filter(|c| c.len() == 6)
+    else {
+        return Err(PuzzleError::malformed(format!(
+            "expected a colour like (#70c710), got {colour:?}"
+        )));
+    };
+    let (distance, direction) = hex.split_at(5);
+
+    let distance = isize::from_str_radix(distance, 16)?;
+    let direction = match direction {
+        "0" => Direction::Right,
+        "1" => Direction::Down,
+        "2" => Direction::Left,
+        "3" => Direction::Up,
+        _ => {
+            return Err(PuzzleError::malformed(format!(
+                "unrecognised direction digit {direction:?}"
+            )))
+        }
+    };
+
+    Ok((direction, distance))
+}
+
+fn parse_puzzle(input: &str) -> PuzzleResult<Vec<Record>> {
+    parse_lines(input, Record::from_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let dig_plan = Day18::parse("test_part1").unwrap();
+        let result = Day18.part_one(&dig_plan);
+        assert_eq!(result, 62);
+    }
+
+    #[test]
+    fn part_two() {
+        let dig_plan = Day18::parse("test_part1").unwrap();
+        let result = Day18.part_two(&dig_plan);
+        assert_eq!(result, 952408144115);
+    }
+}
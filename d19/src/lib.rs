@@ -0,0 +1,364 @@
+use std::collections::HashMap;
+
+use aoc_utils::{
+    interval::HyperRect, parse_nom_line, search::flood_fill, PuzzleError, PuzzleResult, Solution,
+    Solver,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, char, digit1},
+    combinator::{map, map_res, value},
+    multi::separated_list1,
+    sequence::{delimited, tuple},
+    IResult,
+};
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day19>(19)
+}
+
+#[derive(Debug, Default)]
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Workflows, Vec<Part>);
+    type Output = usize;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        parse_puzzle(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        let (workflows, parts) = input;
+        rating_sum(workflows, parts)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        let (workflows, _) = input;
+        rating_combinations(workflows)
+    }
+}
+
+pub fn rating_sum(workflows: &Workflows, parts: &[Part]) -> usize {
+    let mut accepted_sum: usize = 0;
+    for part in parts {
+        let mut destination = "in".to_string();
+        loop {
+            let workflow = &workflows[&destination];
+            destination = evaluate_workflow(workflow, part);
+            if &destination == "A" {
+                accepted_sum += part.total_rating();
+                break;
+            } else if &destination == "R" {
+                break;
+            }
+        }
+    }
+
+    accepted_sum
+}
+
+pub fn rating_combinations(workflows: &Workflows) -> usize {
+    let start_range = PartRange::new([1..4001, 1..4001, 1..4001, 1..4001]);
+    let reached = flood_fill([("in".to_owned(), start_range)], |(workflow, range)| {
+        let mut next = vec![];
+        if workflow == "A" || workflow == "R" {
+            return next;
+        }
+        let mut range = range.clone();
+        for rule in &workflows[workflow] {
+            match rule {
+                Rule::Destination(d) => {
+                    next.push((d.to_owned(), range));
+                    break;
+                }
+                Rule::Comparison(c) => {
+                    let (accepted, rejected) = c.split(&range);
+                    if let Some(accepted) = accepted {
+                        next.push((c.destination.clone(), accepted));
+                    }
+                    match rejected {
+                        Some(rejected) => range = rejected,
+                        None => break,
+                    }
+                }
+            }
+        }
+        next
+    });
+    reached
+        .iter()
+        .filter(|(workflow, _)| workflow == "A")
+        .map(|(_, range)| range.volume())
+        .sum()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Part {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+impl Part {
+    fn parse(input: &str) -> IResult<&str, Self> {
+        let (input, (_, x, _)) = tuple((tag("{x="), number, tag(",")))(input)?;
+        let (input, (_, m, _)) = tuple((tag("m="), number, tag(",")))(input)?;
+        let (input, (_, a, _)) = tuple((tag("a="), number, tag(",")))(input)?;
+        let (input, (_, s, _)) = tuple((tag("s="), number, tag("}")))(input)?;
+
+        Ok((input, Self { x, m, a, s }))
+    }
+
+    fn total_rating(&self) -> usize {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+/// Every combination of x, m, a and s ratings still in play.
+type PartRange = HyperRect<usize, 4>;
+
+#[derive(Debug, Clone, Copy)]
+enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl Category {
+    /// The category's axis in a `PartRange`.
+    const fn axis(self) -> usize {
+        match self {
+            Self::X => 0,
+            Self::M => 1,
+            Self::A => 2,
+            Self::S => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Comparison {
+    LessThan,
+    GreaterThan,
+}
+
+#[derive(Debug, Clone)]
+pub struct ComparisonRule {
+    category: Category,
+    comparison: Comparison,
+    value: usize,
+    destination: String,
+}
+
+impl ComparisonRule {
+    fn parse(input: &str) -> IResult<&str, Self> {
+        let category = alt((
+            value(Category::X, char('x')),
+            value(Category::M, char('m')),
+            value(Category::A, char('a')),
+            value(Category::S, char('s')),
+        ));
+        let comparison = alt((
+            value(Comparison::LessThan, char('<')),
+            value(Comparison::GreaterThan, char('>')),
+        ));
+        let (input, (category, comparison, value, _, destination)) =
+            tuple((category, comparison, number, tag(":"), alpha1))(input)?;
+
+        Ok((
+            input,
+            Self {
+                category,
+                comparison,
+                value,
+                destination: destination.to_string(),
+            },
+        ))
+    }
+
+    fn evaluate(&self, part: &Part) -> Option<String> {
+        let category = match self.category {
+            Category::A => part.a,
+            Category::M => part.m,
+            Category::S => part.s,
+            Category::X => part.x,
+        };
+
+        let condition = match self.comparison {
+            Comparison::GreaterThan => category > self.value,
+            Comparison::LessThan => category < self.value,
+        };
+
+        if condition {
+            Some(self.destination.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Splits `part_range` into the parts that do and don't pass the rule.
+    fn split(&self, part_range: &PartRange) -> (Option<PartRange>, Option<PartRange>) {
+        let axis = self.category.axis();
+        match self.comparison {
+            Comparison::LessThan => part_range.split_at(axis, self.value),
+            Comparison::GreaterThan => {
+                let (rejected, accepted) = part_range.split_at(axis, self.value + 1);
+                (accepted, rejected)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Rule {
+    Comparison(ComparisonRule),
+    Destination(String),
+}
+
+impl Rule {
+    fn parse(input: &str) -> IResult<&str, Self> {
+        alt((
+            map(ComparisonRule::parse, Self::Comparison),
+            map(alpha1, |d: &str| Self::Destination(d.to_string())),
+        ))(input)
+    }
+
+    fn evaluate(&self, part: &Part) -> Option<String> {
+        match self {
+            Self::Comparison(r) => r.evaluate(part),
+            Self::Destination(d) => Some(d.clone()),
+        }
+    }
+}
+
+pub type Workflow = Vec<Rule>;
+pub type Workflows = HashMap<String, Workflow>;
+
+fn evaluate_workflow(workflow: &Workflow, part: &Part) -> String {
+    for rule in workflow {
+        let destination = rule.evaluate(part);
+        if let Some(destination) = destination {
+            return destination;
+        }
+    }
+    unreachable!("parsing checks every workflow ends in a destination")
+}
+
+fn number(input: &str) -> IResult<&str, usize> {
+    map_res(digit1, str::parse)(input)
+}
+
+fn parse_workflow(input: &str) -> IResult<&str, (String, Workflow)> {
+    let (input, (name, rules)) = tuple((
+        alpha1,
+        delimited(tag("{"), separated_list1(tag(","), Rule::parse), tag("}")),
+    ))(input)?;
+    Ok((input, (name.to_string(), rules)))
+}
+
+/// Checks a workflow that has already parsed ends in a rule that always
+/// applies, and only sends parts to other workflows, `A` or `R`.
+fn check_workflow(line: &str, workflows: &Workflows) -> PuzzleResult<()> {
+    let Some((name, rules)) = line.strip_suffix('}').and_then(|l| l.split_once('{')) else {
+        return Err(PuzzleError::malformed("expected a workflow"));
+    };
+
+    // The column each rule starts at, counting from one.
+    let mut column = name.len() + 2;
+    let mut last = (column, false);
+    for rule in rules.split(',') {
+        let (destination, offset) = match rule.split_once(':') {
+            Some((comparison, destination)) => (destination, comparison.len() + 1),
+            None => (rule, 0),
+        };
+        if !(destination == "A" || destination == "R" || workflows.contains_key(destination)) {
+            return Err(
+                PuzzleError::malformed(format!("no workflow called {destination:?}"))
+                    .at_column(column + offset),
+            );
+        }
+        last = (column, offset > 0);
+        column += rule.len() + 1;
+    }
+    if let (column, true) = last {
+        return Err(PuzzleError::malformed(
+            "expected the last rule to be a destination that always applies",
+        )
+        .at_column(column));
+    }
+    Ok(())
+}
+
+fn parse_puzzle(input: &str) -> PuzzleResult<(Workflows, Vec<Part>)> {
+    let mut lines = input.lines().enumerate();
+
+    let mut workflow_map = HashMap::new();
+    let mut workflow_lines = vec![];
+    for (i, line) in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+        let (name, workflow) =
+            parse_nom_line(line, parse_workflow).map_err(|e| e.at_line(i + 1))?;
+        workflow_map.insert(name, workflow);
+        workflow_lines.push((i + 1, line));
+    }
+    if !workflow_map.contains_key("in") {
+        return Err(PuzzleError::malformed("missing the \"in\" workflow"));
+    }
+    for (line_number, line) in workflow_lines {
+        check_workflow(line, &workflow_map).map_err(|e| e.at_line(line_number))?;
+    }
+
+    let parts = lines
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| parse_nom_line(line, Part::parse).map_err(|e| e.at_line(i + 1)))
+        .collect::<PuzzleResult<_>>()?;
+
+    Ok((workflow_map, parts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let puzzle = Day19::parse("test_part1").unwrap();
+        let result = Day19.part_one(&puzzle);
+        assert_eq!(result, 19114);
+    }
+
+    #[test]
+    fn part_two() {
+        let puzzle = Day19::parse("test_part1").unwrap();
+        let result = Day19.part_two(&puzzle);
+        assert_eq!(result, 167409079868000);
+    }
+
+    #[test]
+    fn parse_bad_category() {
+        let error = parse_puzzle("in{q>10:A,R}\n\n{x=1,m=2,a=3,s=4}\n").unwrap_err();
+        assert_eq!(error.line(), Some(1));
+        assert_eq!(error.column(), Some(5));
+    }
+
+    #[test]
+    fn parse_unknown_destination() {
+        let error = parse_puzzle("in{x<10:A,foo}\n\n{x=1,m=2,a=3,s=4}\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (Some(1), Some(11)));
+        let error = parse_puzzle("in{x<10:px,R}\npx{A}\nqq{m>5:zz,A}\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (Some(3), Some(8)));
+    }
+
+    #[test]
+    fn parse_missing_fallback() {
+        let error = parse_puzzle("in{x<10:R,s>3:A}\n\n{x=1,m=2,a=3,s=4}\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (Some(1), Some(11)));
+    }
+}
@@ -0,0 +1,616 @@
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap, HashMap, HashSet},
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use aoc_utils::{parse_lines, search::walk, PuzzleError, PuzzleResult, Solution, Solver};
+use clap::Args;
+use num::integer::lcm;
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day20>(20)
+}
+
+#[derive(Debug, Args)]
+pub struct Day20 {
+    /// How many times to press the button in part one.
+    #[arg(long, default_value_t = Day20::default().presses)]
+    pub presses: usize,
+    /// The most times to press the button in part two before giving up.
+    #[arg(long, default_value_t = Day20::default().max_presses)]
+    pub max_presses: usize,
+}
+
+impl Default for Day20 {
+    fn default() -> Self {
+        Self {
+            presses: 1000,
+            max_presses: 1_000_000,
+        }
+    }
+}
+
+impl Solution for Day20 {
+    type Input = HashMap<Name, Module>;
+    type Output = Presses;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        parse_puzzle(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        Presses(Ok(pulse_multiple(input, self.presses)))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        Presses(pulse_rx(input, self.max_presses))
+    }
+}
+
+pub fn pulse_multiple(module_map: &HashMap<Name, Module>, presses: usize) -> usize {
+    let mut network = Network::new(module_map.clone());
+    let mut pulse_count = PulseCounter { low: 0, high: 0 };
+    for _ in 0..presses {
+        for sent in network.press_button() {
+            pulse_count.increment(sent.pulse);
+        }
+    }
+
+    pulse_count.pulse_multiple()
+}
+
+/// Why `rx` is never sent a low pulse, or at least not soon enough to find.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RxError {
+    /// No module sends pulses to `rx`.
+    NoFeeder,
+    /// The module sending pulses to `rx` isn't a conjunction.
+    NotConjunction(Name),
+    /// An input to the conjunction feeding `rx` first sends it high partway
+    /// through its cycle, so the cycles can't simply be lined up.
+    OffCycle(Name),
+    /// Every module's memory came back round to an earlier press without `rx`
+    /// being sent a low pulse, so it never will be.
+    Repeats { presses: usize },
+    /// `rx` still hadn't been sent a low pulse after this many presses.
+    TooManyPresses(usize),
+}
+
+impl fmt::Display for RxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoFeeder => write!(f, "no module sends pulses to rx"),
+            Self::NotConjunction(name) => {
+                write!(f, "rx is fed by {name}, which isn't a conjunction")
+            }
+            Self::OffCycle(name) => write!(f, "{name} doesn't start on its cycle"),
+            Self::Repeats { presses } => write!(
+                f,
+                "the network repeats after {presses} presses without sending rx a low pulse"
+            ),
+            Self::TooManyPresses(presses) => {
+                write!(f, "rx wasn't sent a low pulse in {presses} presses")
+            }
+        }
+    }
+}
+
+/// An answer to either part, or why part two doesn't have one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Presses(pub Result<usize, RxError>);
+
+impl fmt::Display for Presses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Ok(answer) => write!(f, "{answer}"),
+            Err(e) => write!(f, "no answer, {e}"),
+        }
+    }
+}
+
+/// The fewest button presses before `rx` is sent a low pulse.
+///
+/// `rx` is fed by a single conjunction, which only sends low once every one
+/// of its inputs has most recently sent it high. Each input does that on a
+/// fixed cycle, so the answer is where all those cycles line up. Pressing
+/// stops early if the whole network's memory repeats, as nothing new can
+/// happen after that.
+///
+/// # Errors
+/// If `rx` isn't fed by a conjunction, an input's first high pulse isn't at
+/// the end of its first cycle, or `rx` is never sent a low pulse within
+/// `max_presses` presses.
+pub fn pulse_rx(module_map: &HashMap<Name, Module>, max_presses: usize) -> Result<usize, RxError> {
+    let feeder = rx_feeder(module_map).ok_or(RxError::NoFeeder)?;
+    let ModuleType::Conjunction(conjunction) = &module_map[feeder].type_ else {
+        return Err(RxError::NotConjunction(feeder.clone()));
+    };
+
+    let mut high_presses: HashMap<&str, Vec<usize>> = conjunction
+        .incoming
+        .keys()
+        .map(|name| (name.as_str(), vec![]))
+        .collect();
+
+    let mut network = Network::new(module_map.clone());
+    let mut seen_states = HashSet::from([network.state_hash()]);
+    for presses in 1..=max_presses {
+        let mut rx_low = false;
+        network.press_button_with(|sent| {
+            if sent.to == "rx" && sent.pulse == Pulse::Low {
+                rx_low = true;
+            } else if sent.to == *feeder && sent.pulse == Pulse::High {
+                if let Some(seen) = high_presses.get_mut(sent.from.as_str()) {
+                    if seen.last() != Some(&presses) {
+                        seen.push(presses);
+                    }
+                }
+            }
+        });
+        if rx_low {
+            return Ok(presses);
+        }
+        if high_presses.values().all(|seen| seen.len() >= 2) {
+            return high_presses
+                .into_iter()
+                .map(|(name, seen)| {
+                    let cycle = seen[1] - seen[0];
+                    if seen[0] == cycle {
+                        Ok(cycle)
+                    } else {
+                        Err(RxError::OffCycle(name.to_owned()))
+                    }
+                })
+                .try_fold(1, |total, cycle| Ok(lcm(total, cycle?)));
+        }
+        if !seen_states.insert(network.state_hash()) {
+            return Err(RxError::Repeats { presses });
+        }
+    }
+    Err(RxError::TooManyPresses(max_presses))
+}
+
+/// The name of the module that sends pulses to `rx`, if there is one.
+fn rx_feeder(module_map: &HashMap<Name, Module>) -> Option<&Name> {
+    module_map
+        .iter()
+        .find(|(_, m)| m.destinations.iter().any(|d| d == "rx"))
+        .map(|(name, _)| name)
+}
+
+/// Renders the module graph as a Graphviz digraph.
+///
+/// Flip-flops are boxes, conjunctions diamonds and the broadcaster a double
+/// circle. The module feeding `rx` is drawn in red.
+#[must_use]
+pub fn to_dot(module_map: &HashMap<Name, Module>) -> String {
+    let feeder = rx_feeder(module_map);
+    let mut names: Vec<_> = module_map.keys().collect();
+    names.sort();
+
+    let mut dot = String::from("digraph modules {\n");
+    for name in &names {
+        let shape = match module_map[*name].type_ {
+            ModuleType::Broadcaster => "doublecircle",
+            ModuleType::FlipFlop(_) => "box",
+            ModuleType::Conjunction(_) => "diamond",
+        };
+        let highlight = if Some(*name) == feeder {
+            ", color=red, penwidth=2"
+        } else {
+            ""
+        };
+        dot.push_str(&format!("    \"{name}\" [shape={shape}{highlight}];\n"));
+    }
+    for name in &names {
+        for destination in &module_map[*name].destinations {
+            dot.push_str(&format!("    \"{name}\" -> \"{destination}\";\n"));
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// A pulse travelling between two modules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SentPulse {
+    pub from: Name,
+    pub to: Name,
+    pub pulse: Pulse,
+}
+
+impl fmt::Display for SentPulse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} -{}-> {}", self.from, self.pulse, self.to)
+    }
+}
+
+/// The modules wired together, keeping every flip-flop and conjunction's
+/// memory between button presses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Network {
+    modules: HashMap<Name, Module>,
+}
+
+impl Network {
+    #[must_use]
+    pub fn new(modules: HashMap<Name, Module>) -> Self {
+        Self { modules }
+    }
+
+    /// Presses the button once, returning every pulse in the order it was sent.
+    pub fn press_button(&mut self) -> Vec<SentPulse> {
+        let mut sent = vec![];
+        self.press_button_with(|pulse| sent.push(pulse.clone()));
+        sent
+    }
+
+    /// Presses the button once, calling `observe` with each pulse sent to `module`.
+    pub fn press_button_observing(&mut self, module: &str, mut observe: impl FnMut(&SentPulse)) {
+        self.press_button_with(|sent| {
+            if sent.to == module {
+                observe(sent);
+            }
+        });
+    }
+
+    fn press_button_with(&mut self, mut observe: impl FnMut(&SentPulse)) {
+        let button = SentPulse {
+            from: "button".to_owned(),
+            to: "broadcaster".to_owned(),
+            pulse: Pulse::Low,
+        };
+
+        walk([button], |sent| {
+            observe(&sent);
+            let Some(module) = self.modules.get_mut(&sent.to) else {
+                return vec![];
+            };
+
+            let output = match &mut module.type_ {
+                ModuleType::Broadcaster => Some(sent.pulse),
+                ModuleType::Conjunction(ref mut c) => Some(c.process(&sent.from, sent.pulse)),
+                ModuleType::FlipFlop(ref mut f) => f.process(sent.pulse),
+            };
+            let Some(pulse) = output else {
+                return vec![];
+            };
+            module
+                .destinations
+                .iter()
+                .map(|next_dest| SentPulse {
+                    from: sent.to.clone(),
+                    to: next_dest.to_owned(),
+                    pulse,
+                })
+                .collect()
+        });
+    }
+
+    /// A snapshot of every flip-flop and conjunction's memory.
+    #[must_use]
+    pub fn state(&self) -> NetworkState {
+        let modules = self
+            .modules
+            .iter()
+            .filter_map(|(name, module)| {
+                let state = match &module.type_ {
+                    ModuleType::Broadcaster => return None,
+                    ModuleType::FlipFlop(f) => ModuleState::FlipFlop(f.on),
+                    ModuleType::Conjunction(c) => ModuleState::Conjunction(
+                        c.incoming.iter().map(|(n, p)| (n.clone(), *p)).collect(),
+                    ),
+                };
+                Some((name.clone(), state))
+            })
+            .collect();
+        NetworkState(modules)
+    }
+
+    /// A hash of `state`, cheap to store when looking for a repeat.
+    #[must_use]
+    pub fn state_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.state().hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ModuleState {
+    FlipFlop(bool),
+    Conjunction(BTreeMap<Name, Pulse>),
+}
+
+/// The memory of every stateful module, comparable across presses.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NetworkState(BTreeMap<Name, ModuleState>);
+
+pub type Name = String;
+type Destinations = Vec<Name>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FlipFlop {
+    on: bool,
+}
+
+impl FlipFlop {
+    fn new() -> Self {
+        Self { on: false }
+    }
+
+    fn process(&mut self, in_pulse: Pulse) -> Option<Pulse> {
+        match in_pulse {
+            Pulse::High => None,
+            Pulse::Low => {
+                if self.on {
+                    self.on = false;
+                    Some(Pulse::Low)
+                } else {
+                    self.on = true;
+                    Some(Pulse::High)
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Conjunction {
+    incoming: HashMap<String, Pulse>,
+}
+
+impl Conjunction {
+    fn new() -> Self {
+        Self {
+            incoming: HashMap::new(),
+        }
+    }
+
+    fn process(&mut self, incoming: &str, in_pulse: Pulse) -> Pulse {
+        self.incoming.insert(incoming.to_owned(), in_pulse);
+
+        if self.incoming.values().all(|p| matches!(p, Pulse::High)) {
+            Pulse::Low
+        } else {
+            Pulse::High
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ModuleType {
+    Broadcaster,
+    FlipFlop(FlipFlop),
+    Conjunction(Conjunction),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Module {
+    type_: ModuleType,
+    destinations: Destinations,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Pulse {
+    High,
+    Low,
+}
+
+impl fmt::Display for Pulse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::High => write!(f, "high"),
+            Self::Low => write!(f, "low"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct PulseCounter {
+    low: usize,
+    high: usize,
+}
+
+impl PulseCounter {
+    fn increment(&mut self, pulse: Pulse) {
+        match pulse {
+            Pulse::High => self.high += 1,
+            Pulse::Low => self.low += 1,
+        };
+    }
+
+    fn pulse_multiple(&self) -> usize {
+        self.low * self.high
+    }
+}
+
+fn parse_module(line: &str) -> PuzzleResult<(Name, Module)> {
+    let Some((module_name, destinations)) = line.split_once(" -> ") else {
+        return Err(PuzzleError::malformed(
+            "expected a module and its destinations",
+        ));
+    };
+    let destinations: Vec<String> = destinations.split(", ").map(|s| s.to_string()).collect();
+
+    let (name, type_) = if module_name == "broadcaster" {
+        (module_name, ModuleType::Broadcaster)
+    } else if let Some(name) = module_name.strip_prefix('%') {
+        (name, ModuleType::FlipFlop(FlipFlop::new()))
+    } else if let Some(name) = module_name.strip_prefix('&') {
+        (name, ModuleType::Conjunction(Conjunction::new()))
+    } else {
+        return Err(PuzzleError::malformed(format!(
+            "unrecognised module {module_name:?}"
+        )));
+    };
+
+    Ok((
+        name.to_string(),
+        Module {
+            type_,
+            destinations,
+        },
+    ))
+}
+
+fn parse_puzzle(input: &str) -> PuzzleResult<HashMap<Name, Module>> {
+    let mut module_map: HashMap<Name, Module> =
+        parse_lines(input, parse_module)?.into_iter().collect();
+
+    for (name, module) in &module_map.clone() {
+        for destination in &module.destinations {
+            if let Some(dest_module) = module_map.get_mut(destination) {
+                if let ModuleType::Conjunction(c) = &mut dest_module.type_ {
+                    c.incoming.insert(name.to_owned(), Pulse::Low);
+                };
+            };
+        }
+    }
+
+    Ok(module_map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_example_one() {
+        let module_map = Day20::parse("test1_part1").unwrap();
+        let result = Day20::default().part_one(&module_map);
+        assert_eq!(result, Presses(Ok(32000000)));
+    }
+
+    #[test]
+    fn part_one_example_two() {
+        let module_map = Day20::parse("test2_part1").unwrap();
+        let result = Day20::default().part_one(&module_map);
+        assert_eq!(result, Presses(Ok(11687500)));
+    }
+
+    #[test]
+    fn part_two() {
+        let module_map = Day20::parse("test_part2").unwrap();
+        let result = Day20::default().part_two(&module_map);
+        assert_eq!(result, Presses(Ok(15)));
+    }
+
+    #[test]
+    fn part_two_without_rx() {
+        let module_map = Day20::parse("test1_part1").unwrap();
+        let result = Day20::default().part_two(&module_map);
+        assert_eq!(result, Presses(Err(RxError::NoFeeder)));
+        assert_eq!(
+            result.to_string(),
+            "no answer, no module sends pulses to rx"
+        );
+    }
+
+    #[test]
+    fn part_two_stops_when_the_network_repeats() {
+        // `b` has no inputs, so never sends `hub` a high pulse.
+        let module_map =
+            Day20::parse_str("broadcaster -> a\n%a -> hub\n&b -> hub\n&hub -> rx\n").unwrap();
+        assert_eq!(
+            pulse_rx(&module_map, 100),
+            Err(RxError::Repeats { presses: 2 })
+        );
+    }
+
+    #[test]
+    fn press_button_trace() {
+        let module_map = Day20::parse("test1_part1").unwrap();
+        let mut network = Network::new(module_map);
+        let trace: Vec<_> = network
+            .press_button()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            trace,
+            [
+                "button -low-> broadcaster",
+                "broadcaster -low-> a",
+                "broadcaster -low-> b",
+                "broadcaster -low-> c",
+                "a -high-> b",
+                "b -high-> c",
+                "c -high-> inv",
+                "inv -low-> a",
+                "a -low-> b",
+                "b -low-> c",
+                "c -low-> inv",
+                "inv -high-> a",
+            ]
+        );
+    }
+
+    #[test]
+    fn observe_pulses_into_a_conjunction() {
+        let module_map = Day20::parse("test2_part1").unwrap();
+        let mut network = Network::new(module_map);
+        let observed: Vec<Vec<String>> = (0..4)
+            .map(|_| {
+                let mut pulses = vec![];
+                network.press_button_observing("inv", |sent| pulses.push(sent.to_string()));
+                pulses
+            })
+            .collect();
+        assert_eq!(
+            observed,
+            [
+                ["a -high-> inv"],
+                ["a -low-> inv"],
+                ["a -high-> inv"],
+                ["a -low-> inv"],
+            ]
+        );
+    }
+
+    #[test]
+    fn network_returns_to_initial_state() {
+        let module_map = Day20::parse("test2_part1").unwrap();
+        let mut network = Network::new(module_map);
+        let initial = network.state_hash();
+        let returns: Vec<_> = (1..=8)
+            .filter(|_| {
+                network.press_button();
+                network.state_hash() == initial
+            })
+            .collect();
+        assert_eq!(returns, [4, 8]);
+    }
+
+    #[test]
+    fn dot_graph() {
+        let module_map = Day20::parse("test2_part1").unwrap();
+        assert_eq!(
+            to_dot(&module_map),
+            "digraph modules {
+    \"a\" [shape=box];
+    \"b\" [shape=box];
+    \"broadcaster\" [shape=doublecircle];
+    \"con\" [shape=diamond];
+    \"inv\" [shape=diamond];
+    \"a\" -> \"inv\";
+    \"a\" -> \"con\";
+    \"b\" -> \"con\";
+    \"broadcaster\" -> \"a\";
+    \"con\" -> \"output\";
+    \"inv\" -> \"b\";
+}
+"
+        );
+    }
+
+    #[test]
+    fn dot_marks_rx_feeder() {
+        let module_map = Day20::parse("test_part2").unwrap();
+        let dot = to_dot(&module_map);
+        assert!(dot.contains("    \"hub\" [shape=diamond, color=red, penwidth=2];\n"));
+        assert!(dot.contains("    \"hub\" -> \"rx\";\n"));
+    }
+}
@@ -0,0 +1,340 @@
+use std::fmt::Debug;
+
+use aoc_utils::{
+    search::{bfs, bfs_within},
+    Coord, Direction, FromChar, Grid, PuzzleError, PuzzleResult, Solution, Solver,
+};
+
+#[must_use]
+pub fn solver() -> Solver {
+    Solver::from_solution::<Day21>(21)
+}
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Left,
+    Direction::Down,
+    Direction::Right,
+];
+
+#[derive(Debug)]
+pub struct Day21 {
+    pub step_limit: usize,
+    pub infinite_step_limit: usize,
+}
+
+impl Default for Day21 {
+    fn default() -> Self {
+        Self {
+            step_limit: 64,
+            infinite_step_limit: 26501365,
+        }
+    }
+}
+
+impl Solution for Day21 {
+    type Input = (Coord, Garden);
+    type Output = usize;
+
+    fn parse_str(input: &str) -> PuzzleResult<Self::Input> {
+        parse_puzzle(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Self::Output {
+        let (start, garden) = input;
+        garden_plots(*start, garden, self.step_limit)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Self::Output {
+        let (start, garden) = input;
+        infinite_garden_plots(*start, garden, self.infinite_step_limit)
+    }
+}
+
+pub fn garden_plots(start: Coord, garden: &Garden, step_limit: usize) -> usize {
+    DistanceMap::bounded(start, garden).plots_reached(step_limit)
+}
+
+/// How many plots can be stood on after exactly any number of steps, from a
+/// single search of the garden.
+///
+/// Any plot reached early can be returned to two steps later, so the plots
+/// reached in exactly `n` steps are those whose distance is at most `n` and
+/// has the same parity as `n`.
+#[derive(Debug, Clone)]
+pub struct DistanceMap {
+    /// Plots at most `n` steps away with the same parity as `n`, indexed by `n`.
+    reached: Vec<usize>,
+    /// Whether every reachable plot was searched, rather than stopping early.
+    complete: bool,
+}
+
+impl DistanceMap {
+    /// Searches every plot reachable in the garden.
+    #[must_use]
+    pub fn bounded(start: Coord, garden: &Garden) -> Self {
+        let distances = bfs([start], |position| {
+            garden
+                .neighbours(*position)
+                .filter(|next| garden[*next] == Terrain::Garden)
+                .collect::<Vec<_>>()
+        });
+        Self::new(distances.distances().map(|(_, steps)| steps), 0, true)
+    }
+
+    /// Searches plots up to `max_steps` away when the garden repeats forever.
+    #[must_use]
+    pub fn tiled(start: Coord, garden: &Garden, max_steps: usize) -> Self {
+        let distances = bfs_within([start], max_steps, |position| {
+            DIRECTIONS
+                .iter()
+                .map(|direction| direction.next_coord(position))
+                .filter(|next| {
+                    let row = modulo(next.0, garden.height());
+                    let column = modulo(next.1, garden.width());
+                    garden[(row as isize, column as isize)] == Terrain::Garden
+                })
+                .collect::<Vec<_>>()
+        });
+        Self::new(
+            distances.distances().map(|(_, steps)| steps),
+            max_steps,
+            false,
+        )
+    }
+
+    fn new(distances: impl Iterator<Item = usize>, searched: usize, complete: bool) -> Self {
+        let mut reached = vec![0; searched + 1];
+        for steps in distances {
+            if steps >= reached.len() {
+                reached.resize(steps + 1, 0);
+            }
+            reached[steps] += 1;
+        }
+        for n in 2..reached.len() {
+            reached[n] += reached[n - 2];
+        }
+        Self { reached, complete }
+    }
+
+    /// Plots reachable in exactly `steps` steps.
+    ///
+    /// # Panics
+    /// If the garden is tiled and `steps` is further than was searched.
+    #[must_use]
+    pub fn plots_reached(&self, steps: usize) -> usize {
+        if let Some(&reached) = self.reached.get(steps) {
+            return reached;
+        }
+        assert!(
+            self.complete,
+            "only searched {} steps, not {steps}",
+            self.reached.len() - 1
+        );
+        // Every plot has been found, so the count stops changing for each parity.
+        let last = self.reached.len() - 1;
+        if last % 2 == steps % 2 {
+            self.reached[last]
+        } else {
+            last.checked_sub(1).map_or(0, |n| self.reached[n])
+        }
+    }
+}
+
+/// Plots reachable in exactly `step_limit` steps when the garden repeats forever.
+///
+/// Once the garden has been crossed a few times the count grows
+/// quadratically with every further crossing, so a few searches are enough to
+/// extrapolate to huge step limits. That needs the start to have a clear run to
+/// every edge of a square garden, and the quadratic is fitted on later crossings
+/// and checked against another one before it's trusted. Anything else is
+/// searched directly.
+pub fn infinite_garden_plots(start: Coord, garden: &Garden, step_limit: usize) -> usize {
+    const FIRST_CROSSING: usize = 2;
+
+    let size = garden.height();
+    let remainder = step_limit % size;
+    let crossings = step_limit / size;
+
+    if !extrapolation_applies(start, garden) || crossings <= FIRST_CROSSING + 3 {
+        return DistanceMap::tiled(start, garden, step_limit).plots_reached(step_limit);
+    }
+
+    let search_steps = [0, 1, 2, 3].map(|k| remainder + (FIRST_CROSSING + k) * size);
+    let distances = DistanceMap::tiled(start, garden, search_steps[3]);
+    let samples = search_steps.map(|steps| distances.plots_reached(steps) as i128);
+
+    // Newton's forward differences, counting crossings from the first sample.
+    let first_diff = samples[1] - samples[0];
+    let second_diff = samples[2] - 2 * samples[1] + samples[0];
+    let quadratic = |k: i128| samples[0] + k * first_diff + k * (k - 1) / 2 * second_diff;
+
+    if quadratic(3) != samples[3] {
+        return DistanceMap::tiled(start, garden, step_limit).plots_reached(step_limit);
+    }
+    quadratic((crossings - FIRST_CROSSING) as i128) as usize
+}
+
+/// Whether the garden is square with the start in its centre and nothing
+/// blocking the start's row or column.
+fn extrapolation_applies(start: Coord, garden: &Garden) -> bool {
+    let size = garden.height();
+    let centre = (size / 2) as isize;
+    garden.width() == size
+        && size % 2 == 1
+        && start == (centre, centre)
+        && garden
+            .row(start.0 as usize)
+            .iter()
+            .all(|t| *t == Terrain::Garden)
+        && garden
+            .column(start.1 as usize)
+            .all(|t| *t == Terrain::Garden)
+}
+
+fn modulo(a: isize, b: usize) -> usize {
+    if a >= 0 {
+        (a as usize) % b
+    } else {
+        let r = (!a as usize) % b;
+        b - r - 1
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Terrain {
+    Garden,
+    Rock,
+}
+
+impl FromChar for Terrain {
+    fn from_char(c: char) -> PuzzleResult<Self> {
+        match c {
+            '.' => Ok(Self::Garden),
+            '#' => Ok(Self::Rock),
+            _ => Err(PuzzleError::unexpected_char(c)),
+        }
+    }
+}
+
+impl Debug for Terrain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Garden => write!(f, "."),
+            Self::Rock => write!(f, "#"),
+        }
+    }
+}
+
+pub type Garden = Grid<Terrain>;
+
+fn parse_puzzle(input: &str) -> PuzzleResult<(Coord, Garden)> {
+    let mut start_coord = None;
+    let mut rows = vec![];
+    for (i, row) in input.lines().enumerate() {
+        let mut new_row = vec![];
+        for (j, char) in row.chars().enumerate() {
+            if char == 'S' {
+                start_coord = Some((i as isize, j as isize));
+                new_row.push(Terrain::Garden);
+            } else {
+                let terrain =
+                    Terrain::from_char(char).map_err(|e| e.at_column(j + 1).at_line(i + 1))?;
+                new_row.push(terrain);
+            }
+        }
+        rows.push(new_row);
+    }
+    let Some(start_coord) = start_coord else {
+        return Err(PuzzleError::malformed("missing the starting position 'S'"));
+    };
+    Ok((start_coord, Grid::from_rows(rows)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let puzzle = Day21::parse("test_part1").unwrap();
+        let result = Day21 {
+            step_limit: 6,
+            ..Default::default()
+        }
+        .part_one(&puzzle);
+        assert_eq!(result, 16);
+    }
+
+    #[test]
+    fn part_one_wide() {
+        let puzzle = Day21::parse("test_wide").unwrap();
+        let result = Day21 {
+            step_limit: 6,
+            ..Default::default()
+        }
+        .part_one(&puzzle);
+        assert_eq!(result, 15);
+    }
+
+    #[test]
+    fn part_two() {
+        let puzzle = Day21::parse("test_part1").unwrap();
+        let result = Day21 {
+            infinite_step_limit: 10,
+            ..Default::default()
+        }
+        .part_two(&puzzle);
+        assert_eq!(result, 50);
+    }
+
+    #[test]
+    fn distance_map_answers_any_step_count() {
+        let (start, garden) = Day21::parse("test_part1").unwrap();
+        let bounded = DistanceMap::bounded(start, &garden);
+        assert_eq!(bounded.plots_reached(6), 16);
+        assert_eq!(bounded.plots_reached(0), 1);
+        assert_eq!(bounded.plots_reached(1000), bounded.plots_reached(100));
+        assert_eq!(bounded.plots_reached(1001), bounded.plots_reached(101));
+
+        let tiled = DistanceMap::tiled(start, &garden, 100);
+        let counts = [6, 10, 50, 100].map(|steps| tiled.plots_reached(steps));
+        assert_eq!(counts, [16, 50, 1594, 6536]);
+    }
+
+    #[test]
+    fn part_two_searches_blocked_gardens() {
+        let (start, garden) = Day21::parse("test_part1").unwrap();
+        assert!(!extrapolation_applies(start, &garden));
+        assert_eq!(infinite_garden_plots(start, &garden, 50), 1594);
+        assert_eq!(infinite_garden_plots(start, &garden, 100), 6536);
+    }
+
+    #[test]
+    fn part_two_extrapolates_gardens_with_pockets() {
+        // Fitting on the first three crossings goes wrong here when there are
+        // few steps left over after the last whole crossing.
+        let (start, garden) = Day21::parse("test_pockets").unwrap();
+        assert!(extrapolation_applies(start, &garden));
+        for steps in [66, 77, 78, 88, 100, 121] {
+            assert_eq!(
+                infinite_garden_plots(start, &garden, steps),
+                DistanceMap::tiled(start, &garden, steps).plots_reached(steps),
+                "{steps} steps"
+            );
+        }
+    }
+
+    #[test]
+    fn part_two_extrapolates_clear_gardens() {
+        let (start, garden) = Day21::parse("test_clear").unwrap();
+        assert!(extrapolation_applies(start, &garden));
+        for steps in [49, 60, 71, 77, 82, 98, 100, 111] {
+            assert_eq!(
+                infinite_garden_plots(start, &garden, steps),
+                DistanceMap::tiled(start, &garden, steps).plots_reached(steps),
+                "{steps} steps"
+            );
+        }
+    }
+}